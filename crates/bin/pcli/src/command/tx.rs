@@ -866,6 +866,11 @@ impl TxCmd {
                     .context("can't read proposal file")?;
                 let proposal_toml: ProposalToml =
                     toml::from_str(&proposal_string).context("can't parse proposal file")?;
+                // Files referenced by the proposal are relative to the proposal file.
+                let proposal_toml = match file.parent() {
+                    Some(dir) => proposal_toml.resolve_relative_to(dir.as_std_path()),
+                    None => proposal_toml,
+                };
                 let proposal = proposal_toml
                     .try_into()
                     .context("can't parse proposal file")?;
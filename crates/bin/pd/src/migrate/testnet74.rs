@@ -17,7 +17,7 @@ use crate::network::generate::NetworkConfig;
 
 /// Writes the auction parameters to the chain state.
 async fn write_auction_parameters(delta: &mut StateDelta<Snapshot>) -> anyhow::Result<()> {
-    let params = AuctionParameters {};
+    let params = AuctionParameters::default();
     delta.put_auction_params(params);
     Ok(())
 }
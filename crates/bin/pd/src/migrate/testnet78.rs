@@ -343,6 +343,8 @@ async fn truncate_proposal_fields(delta: &mut StateDelta<Snapshot>) -> anyhow::R
                     },
                 ));
             }
+            pb_governance::proposal::Payload::PauseAuctions(_paused) => {}
+            pb_governance::proposal::Payload::EmergencyUpgrade(_height) => {}
         };
 
         // Store the truncated proposal data
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use penumbra_sdk_governance::StateReadExt as _;
 use penumbra_sdk_shielded_pool::component::Ics20Transfer;
 use penumbra_sdk_transaction::Action;
 use penumbra_sdk_txhash::TransactionContext;
@@ -135,7 +136,13 @@ impl AppActionHandler for Action {
             Action::CommunityPoolSpend(action) => action.check_and_execute(state).await,
             Action::CommunityPoolOutput(action) => action.check_and_execute(state).await,
             Action::CommunityPoolDeposit(action) => action.check_and_execute(state).await,
-            Action::ActionDutchAuctionSchedule(action) => action.check_and_execute(state).await,
+            Action::ActionDutchAuctionSchedule(action) => {
+                anyhow::ensure!(
+                    !state.auctions_paused().await?,
+                    "scheduling new auctions is paused by governance"
+                );
+                action.check_and_execute(state).await
+            }
             Action::ActionDutchAuctionEnd(action) => action.check_and_execute(state).await,
             Action::ActionDutchAuctionWithdraw(action) => action.check_and_execute(state).await,
         }
@@ -13,7 +13,7 @@ use penumbra_sdk_community_pool::component::StateReadExt as _;
 use penumbra_sdk_governance::{
     component::{StateReadExt as _, StateWriteExt as _},
     event,
-    proposal::{Proposal, ProposalPayload},
+    proposal::{check_community_pool_spend_action, Proposal, ProposalPayload},
     proposal_state::State as ProposalState,
     ProposalNft, ProposalSubmit, VotingReceiptToken,
 };
@@ -75,37 +75,16 @@ impl AppActionHandler for ProposalSubmit {
             CommunityPoolSpend { transaction_plan } => {
                 // Check to make sure that the transaction plan contains only valid actions for the
                 // Community Pool (none of them should require proving to build):
-                use penumbra_sdk_transaction::plan::ActionPlan::*;
-
                 let parsed_transaction_plan = TransactionPlan::decode(&transaction_plan[..])
                     .context("transaction plan was malformed")?;
 
-                for action in &parsed_transaction_plan.actions {
-                    match action {
-                        Spend(_) | Output(_) | Swap(_) | SwapClaim(_) | DelegatorVote(_)
-                        | UndelegateClaim(_) => {
-                            anyhow::bail!("invalid action in Community Pool spend proposal (would require proving)")
-                        }
-                        Delegate(_) | Undelegate(_) => {
-                            anyhow::bail!("invalid action in Community Pool spend proposal (can't claim outputs of undelegation)")
-                        }
-                        ProposalSubmit(_) | ProposalWithdraw(_) | ProposalDepositClaim(_) => {
-                            anyhow::bail!("invalid action in Community Pool spend proposal (not allowed to manipulate proposals from within proposals)")
-                        }
-                        ValidatorDefinition(_)
-                        | IbcAction(_)
-                        | ValidatorVote(_)
-                        | PositionOpen(_)
-                        | PositionClose(_)
-                        | PositionWithdraw(_)
-                        | CommunityPoolSpend(_)
-                        | CommunityPoolOutput(_)
-                        | Ics20Withdrawal(_)
-                        | CommunityPoolDeposit(_)
-                        | ActionDutchAuctionSchedule(_)
-                        | ActionDutchAuctionEnd(_)
-                        | ActionDutchAuctionWithdraw(_) => {}
-                    }
+                for action in parsed_transaction_plan
+                    .to_proto()
+                    .actions
+                    .iter()
+                    .filter_map(|action| action.action.as_ref())
+                {
+                    check_community_pool_spend_action(action)?;
                 }
             }
             UpgradePlan { .. } => {}
@@ -117,6 +96,8 @@ impl AppActionHandler for ProposalSubmit {
                 let _ = &ClientId::from_str(client_id)
                     .context("can't decode client id from IBC proposal")?;
             }
+            PauseAuctions { .. } => {}
+            EmergencyUpgrade { .. } => {}
         }
 
         Ok(())
@@ -222,6 +203,18 @@ impl AppActionHandler for ProposalSubmit {
                     .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
                 let _ = state.get_client_state(client_id).await?;
             }
+            ProposalPayload::EmergencyUpgrade { height } => {
+                // An emergency upgrade must target a height that has not yet been reached.
+                let current_height = state.get_block_height().await?;
+                if *height <= current_height {
+                    anyhow::bail!(
+                        "emergency upgrade height {height} is not after the current height {current_height}"
+                    );
+                }
+            }
+            ProposalPayload::PauseAuctions { .. } => {
+                // No stateful checks: pausing auctions that are already paused is a no-op.
+            }
         }
 
         // (end of former check_stateful checks)
@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use anyhow::Context;
 use anyhow::Result;
-use penumbra_sdk_auction::params::AuctionParameters;
+use penumbra_sdk_auction::params::{AuctionParameters, MAX_AUCTION_STEP_COUNT};
 use penumbra_sdk_community_pool::params::CommunityPoolParameters;
 use penumbra_sdk_dex::DexParameters;
 use penumbra_sdk_distributions::params::DistributionsParameters;
@@ -60,7 +60,7 @@ impl AppParameters {
         // Tracked by #3593
         let AppParameters {
             chain_id,
-            auction_params: AuctionParameters {},
+            auction_params: AuctionParameters { max_step_count: _ },
             community_pool_params:
                 CommunityPoolParameters {
                     community_pool_spend_proposals_enabled: _,
@@ -155,7 +155,7 @@ impl AppParameters {
     pub fn check_valid(&self) -> Result<()> {
         let AppParameters {
             chain_id,
-            auction_params: AuctionParameters {},
+            auction_params: AuctionParameters { max_step_count },
             community_pool_params:
                 CommunityPoolParameters {
                     community_pool_spend_proposals_enabled: _,
@@ -274,6 +274,14 @@ impl AppParameters {
                 *min_validator_stake >= 1_000_000u128.into(),
                 "the minimum validator stake must be at least 1penumbra",
             ),
+            (
+                *max_step_count >= 2,
+                "the auction max step count must be at least 2",
+            ),
+            (
+                *max_step_count <= MAX_AUCTION_STEP_COUNT,
+                "the auction max step count must be at most the protocol maximum",
+            ),
         ])
     }
 }
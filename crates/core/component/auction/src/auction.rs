@@ -1,6 +1,8 @@
 pub mod dutch;
 pub mod id;
 pub mod nft;
+pub mod state_view;
 
 pub use id::AuctionId;
-pub use nft::AuctionNft;
+pub use nft::{AuctionNft, AuctionSequence};
+pub use state_view::AuctionStateView;
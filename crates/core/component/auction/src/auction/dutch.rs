@@ -1,16 +1,33 @@
-use std::num::NonZeroU64;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroU64,
+};
 
 use anyhow::anyhow;
 use penumbra_sdk_asset::{asset, Value};
 use penumbra_sdk_dex::lp::position::{self};
 use penumbra_sdk_num::Amount;
 use penumbra_sdk_proto::{core::component::auction::v1 as pb, DomainType};
+use rand_core::CryptoRngCore;
 use serde::{Deserialize, Serialize};
 
-use crate::auction::AuctionId;
+use crate::{
+    auction::AuctionId,
+    params::{AuctionParameters, MAX_AUCTION_STEP_COUNT},
+};
+use actions::schedule::MAX_AUCTION_AMOUNT_RESERVES;
 
 pub mod actions;
-pub use actions::{ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw};
+pub use actions::{
+    positions_retired_on_end, validate_withdraw_seq, ActionDutchAuctionEnd,
+    ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw,
+};
+
+pub mod aggregate;
+pub mod lifecycle;
+pub mod nonce;
+pub mod replay;
+pub mod simulation;
 
 pub const DUTCH_AUCTION_DOMAIN_SEP: &[u8] = b"penumbra_DA_nft";
 
@@ -23,6 +40,189 @@ pub struct DutchAuction {
     pub state: DutchAuctionState,
 }
 
+impl DutchAuction {
+    /// Compute the number of blocks remaining until the auction price steps down,
+    /// relative to the supplied `height`.
+    ///
+    /// Returns `None` if the auction is not active (i.e. it is closed, withdrawn,
+    /// or has no pending trigger), or if the recorded `next_trigger` does not fall
+    /// on the auction's trigger schedule.
+    pub fn blocks_until_next_step(&self, height: u64) -> Option<u64> {
+        if self.state.sequence != 0 {
+            return None;
+        }
+
+        let next_trigger: u64 = self.state.next_trigger?.into();
+        if !self.description.is_trigger_height(next_trigger) {
+            return None;
+        }
+
+        Some(next_trigger.saturating_sub(height))
+    }
+
+    /// Returns whether the chain failed to step the auction, i.e. whether the auction
+    /// is still active but its `next_trigger` was reached by the supplied `height`
+    /// without being advanced.
+    ///
+    /// As with [`DutchAuction::blocks_until_next_step`], the state is expected to have
+    /// been stepped by the end of a trigger height.
+    pub fn is_stuck(&self, height: u64) -> bool {
+        self.state.sequence == 0
+            && self
+                .state
+                .next_trigger
+                .is_some_and(|next_trigger| height >= next_trigger.get())
+    }
+
+    /// Returns whether the auction ended without any of its input being sold.
+    ///
+    /// This can only be detected while the auction is closed: withdrawing an
+    /// auction empties its reserves, after which its fills are no longer
+    /// visible from its state.
+    pub fn ended_unfilled(&self) -> bool {
+        self.state.sequence == 1
+            && self.state.input_reserves == self.description.input.amount
+            && self.state.output_reserves == Amount::zero()
+    }
+
+    /// The output accumulated by the auction so far, or `None` if it hasn't received any.
+    ///
+    /// As with [`DutchAuction::ended_unfilled`], proceeds are only visible until the
+    /// auction is withdrawn, which empties its reserves.
+    pub fn output_value(&self) -> Option<Value> {
+        (self.state.output_reserves > Amount::zero()).then(|| Value {
+            amount: self.state.output_reserves,
+            asset_id: self.description.output_id,
+        })
+    }
+
+    /// The average price at which the auction sold its input, expressed as the amount of
+    /// output asset received per unit of input asset, rounded down.
+    ///
+    /// Returns `None` if nothing was sold, and when the fills can't be recovered from the
+    /// auction state: while a position is deployed, the reserves are held by the position,
+    /// and once the auction is withdrawn, its reserves are emptied.
+    pub fn average_realized_price(&self) -> Option<Amount> {
+        if self.state.sequence > 1 || self.state.current_position.is_some() {
+            return None;
+        }
+
+        let input_sold = self
+            .description
+            .input
+            .amount
+            .checked_sub(&self.state.input_reserves)
+            .filter(|sold| *sold > Amount::zero())?;
+
+        Some(price_per_unit_input(self.state.output_reserves, input_sold))
+    }
+
+    /// The reserves that withdrawing the auction would reclaim, per asset.
+    ///
+    /// Only a closed auction can be withdrawn, so this is empty for auctions that are
+    /// still active or already withdrawn. Assets with no reserves are omitted.
+    pub fn withdrawable_value(&self) -> BTreeMap<asset::Id, Amount> {
+        if self.state.sequence != 1 {
+            return BTreeMap::new();
+        }
+
+        let mut reserves: BTreeMap<asset::Id, Amount> = BTreeMap::new();
+        for (asset_id, amount) in [
+            (self.description.input.asset_id, self.state.input_reserves),
+            (self.description.output_id, self.state.output_reserves),
+        ] {
+            if amount > Amount::zero() {
+                let total = reserves.entry(asset_id).or_default();
+                *total = total.saturating_add(&amount);
+            }
+        }
+        reserves
+    }
+
+    /// A key ordering auctions by end height, then by auction id for determinism.
+    ///
+    /// This is meant to sort "ending soon" lists, e.g. with `sort_by_key`.
+    pub fn sort_key_by_end(&self) -> (u64, AuctionId) {
+        (self.description.end_height, self.description.id())
+    }
+
+    /// Render the wall-clock time remaining until the auction ends, e.g. `"~2h 15m"`, for
+    /// display in UIs.
+    ///
+    /// The time is estimated from the number of blocks remaining, assuming every block takes
+    /// `block_time_secs` seconds. Before the auction starts, this is the time until it does,
+    /// e.g. `"starts in ~45m"`, and once it is closed or past its end height, it is `"ended"`.
+    pub fn countdown(&self, current_height: u64, block_time_secs: u64) -> String {
+        let DutchAuctionDescription {
+            start_height,
+            end_height,
+            ..
+        } = self.description;
+
+        if self.state.sequence != 0 || current_height >= end_height {
+            "ended".to_string()
+        } else if current_height < start_height {
+            let secs = (start_height - current_height).saturating_mul(block_time_secs);
+            format!("starts in {}", approximate_duration(secs))
+        } else {
+            let secs = (end_height - current_height).saturating_mul(block_time_secs);
+            approximate_duration(secs)
+        }
+    }
+
+    /// Flatten the auction into a map of field paths to their rendered values.
+    ///
+    /// This is meant for structured logging and test snapshots, where a flat
+    /// map is easier to diff than the derived `Debug` output. Optional fields
+    /// that are unset are rendered as `"none"`.
+    pub fn to_debug_map(&self) -> BTreeMap<String, String> {
+        let DutchAuctionDescription {
+            input,
+            output_id,
+            max_output,
+            min_output,
+            start_height,
+            end_height,
+            step_count,
+            nonce,
+        } = &self.description;
+        let DutchAuctionState {
+            sequence,
+            current_position,
+            next_trigger,
+            input_reserves,
+            output_reserves,
+        } = &self.state;
+
+        [
+            ("id", self.description.id().to_string()),
+            ("description.input.amount", input.amount.to_string()),
+            ("description.input.asset_id", input.asset_id.to_string()),
+            ("description.output_id", output_id.to_string()),
+            ("description.max_output", max_output.to_string()),
+            ("description.min_output", min_output.to_string()),
+            ("description.start_height", start_height.to_string()),
+            ("description.end_height", end_height.to_string()),
+            ("description.step_count", step_count.to_string()),
+            ("description.nonce", hex::encode(nonce)),
+            ("state.sequence", sequence.to_string()),
+            (
+                "state.current_position",
+                current_position.map_or_else(|| "none".to_string(), |id| id.to_string()),
+            ),
+            (
+                "state.next_trigger",
+                next_trigger.map_or_else(|| "none".to_string(), |h| h.to_string()),
+            ),
+            ("state.input_reserves", input_reserves.to_string()),
+            ("state.output_reserves", output_reserves.to_string()),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect()
+    }
+}
+
 /* Protobuf impls for `DutchAuction` */
 impl DomainType for DutchAuction {
     type Proto = pb::DutchAuction;
@@ -53,6 +253,39 @@ impl TryFrom<pb::DutchAuction> for DutchAuction {
         })
     }
 }
+
+/// A collection of Dutch auctions, for bulk export and storage.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug, Default)]
+#[serde(try_from = "pb::DutchAuctionBatch", into = "pb::DutchAuctionBatch")]
+pub struct DutchAuctionBatch {
+    pub auctions: Vec<DutchAuction>,
+}
+
+impl DomainType for DutchAuctionBatch {
+    type Proto = pb::DutchAuctionBatch;
+}
+
+impl From<DutchAuctionBatch> for pb::DutchAuctionBatch {
+    fn from(domain: DutchAuctionBatch) -> Self {
+        pb::DutchAuctionBatch {
+            auctions: domain.auctions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::DutchAuctionBatch> for DutchAuctionBatch {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::DutchAuctionBatch) -> Result<Self, Self::Error> {
+        Ok(DutchAuctionBatch {
+            auctions: msg
+                .auctions
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
 /* ********************************** */
 
 /// A description of the immutable parts of a dutch auction.
@@ -73,7 +306,151 @@ pub struct DutchAuctionDescription {
 }
 
 impl DutchAuctionDescription {
+    /// Build an auction description from the prices a user would enter, with a
+    /// fresh random nonce.
+    ///
+    /// The prices are expressed as the amount of output asset received per unit
+    /// of input asset, so that `starting_price` and `ending_price` return them back.
+    /// The resulting description is built with [`new_with_random_nonce`](Self::new_with_random_nonce),
+    /// and so is checked with [`check_well_formed`](Self::check_well_formed).
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_user_inputs<R: CryptoRngCore>(
+        input: Value,
+        output_id: asset::Id,
+        start_price: Amount,
+        end_price: Amount,
+        start_height: u64,
+        end_height: u64,
+        step_count: u64,
+        rng: &mut R,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            start_price > end_price && end_price > Amount::zero(),
+            "the start price MUST be greater than the end price, which MUST be positive"
+        );
+
+        let max_output = start_price
+            .checked_mul(&input.amount)
+            .ok_or_else(|| anyhow!("max output amount MUST be less than 52 bits wide"))?;
+        let min_output = end_price * input.amount;
+
+        Self::new_with_random_nonce(
+            input,
+            output_id,
+            max_output,
+            min_output,
+            start_height,
+            end_height,
+            step_count,
+            rng,
+        )
+    }
+
+    /// Build an auction description from its output bounds, with a fresh random nonce, so
+    /// that otherwise-identical auctions get distinct ids.
+    ///
+    /// The resulting description is checked with [`check_well_formed`](Self::check_well_formed).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_random_nonce<R: CryptoRngCore>(
+        input: Value,
+        output_id: asset::Id,
+        max_output: Amount,
+        min_output: Amount,
+        start_height: u64,
+        end_height: u64,
+        step_count: u64,
+        rng: &mut R,
+    ) -> anyhow::Result<Self> {
+        let mut nonce = [0u8; 32];
+        rng.fill_bytes(&mut nonce);
+
+        let description = DutchAuctionDescription {
+            input,
+            output_id,
+            max_output,
+            min_output,
+            start_height,
+            end_height,
+            step_count,
+            nonce,
+        };
+        description.check_well_formed()?;
+        Ok(description)
+    }
+
+    /// Check that the description satisfies the stateless rules the chain applies to
+    /// scheduled auctions, e.g. before building a schedule action for it.
+    pub fn check_well_formed(&self) -> anyhow::Result<()> {
+        let max_reserves = Amount::from(MAX_AUCTION_AMOUNT_RESERVES);
+
+        anyhow::ensure!(
+            self.input.amount > Amount::zero(),
+            "the input amount MUST be positive"
+        );
+        anyhow::ensure!(self.step_count > 0, "the step count MUST be positive");
+        anyhow::ensure!(
+            self.input.amount <= max_reserves,
+            "the input amount MUST be less than 52 bits wide"
+        );
+        anyhow::ensure!(
+            self.input.asset_id != self.output_id,
+            "the input id MUST be different from the output id"
+        );
+        anyhow::ensure!(
+            self.max_output > self.min_output,
+            "the max output MUST be greater than the min output"
+        );
+        anyhow::ensure!(
+            self.min_output > Amount::zero(),
+            "the min output MUST be positive"
+        );
+        anyhow::ensure!(
+            self.max_output <= max_reserves,
+            "the max output amount MUST be less than 52 bits wide"
+        );
+        anyhow::ensure!(
+            self.end_height > self.start_height,
+            "the end height MUST be strictly greater than the start height (start: {}, end: {})",
+            self.start_height,
+            self.end_height
+        );
+        anyhow::ensure!(
+            (2..=MAX_AUCTION_STEP_COUNT).contains(&self.step_count),
+            "the step count MUST be between 2 and {MAX_AUCTION_STEP_COUNT} (got: {})",
+            self.step_count
+        );
+        anyhow::ensure!(
+            (self.end_height - self.start_height) % self.step_count == 0,
+            "the block window ({} blocks) MUST be a multiple of the step count ({})",
+            self.end_height - self.start_height,
+            self.step_count
+        );
+        Ok(())
+    }
+
+    /// Check that the auction window doesn't span the height of a scheduled upgrade, if any.
+    ///
+    /// The chain halts at the upgrade height, which would disrupt the execution of an auction
+    /// running across it. This is an advisory check for clients, the chain doesn't enforce it.
+    pub fn validate_against_upgrade(&self, upgrade_height: Option<u64>) -> anyhow::Result<()> {
+        if let Some(upgrade_height) = upgrade_height {
+            anyhow::ensure!(
+                !(self.start_height..=self.end_height).contains(&upgrade_height),
+                "the auction window (heights {} to {}) spans the upgrade scheduled at height {}",
+                self.start_height,
+                self.end_height,
+                upgrade_height
+            );
+        }
+        Ok(())
+    }
+
     /// Compute the unique identifier for the auction description.
+    ///
+    /// The identifier is the BLAKE2b hash, personalized with [`DUTCH_AUCTION_DOMAIN_SEP`], of the
+    /// nonce, the input, the maximum output, and the start height, end height and step count of
+    /// the auction. It doesn't commit to the output asset or to the minimum output, so that the
+    /// ids of existing auctions stay stable; the nonce is what keeps ids unique.
     pub fn id(&self) -> AuctionId {
         let mut state = blake2b_simd::Params::default()
             .personal(DUTCH_AUCTION_DOMAIN_SEP)
@@ -92,6 +469,232 @@ impl DutchAuctionDescription {
         bytes[0..32].copy_from_slice(&hash.as_bytes()[0..32]);
         AuctionId(bytes)
     }
+
+    /// The asset being sold by the auction.
+    pub fn input_asset_id(&self) -> asset::Id {
+        self.input.asset_id
+    }
+
+    /// The asset the auction is selling its input for.
+    pub fn output_asset_id(&self) -> asset::Id {
+        self.output_id
+    }
+
+    /// The price offered at the start of the auction, expressed as the amount
+    /// of output asset received per unit of input asset.
+    ///
+    /// This is `max_output / input`, rounded down. A description with a zero
+    /// input amount (which is rejected by validation) has a price of zero.
+    pub fn starting_price(&self) -> Amount {
+        price_per_unit_input(self.max_output, self.input.amount)
+    }
+
+    /// The price offered at the end of the auction, expressed as the amount
+    /// of output asset received per unit of input asset.
+    ///
+    /// This is `min_output / input`, rounded down. A description with a zero
+    /// input amount (which is rejected by validation) has a price of zero.
+    pub fn ending_price(&self) -> Amount {
+        price_per_unit_input(self.min_output, self.input.amount)
+    }
+
+    /// The range of output the seller could receive if the auction is fully filled, as a
+    /// `(worst_case, best_case)` pair.
+    ///
+    /// The worst case is `min_output`, when the input is sold at the ending price, and the best
+    /// case is `max_output`, when it is sold at the starting price.
+    pub fn proceeds_range(&self) -> (Amount, Amount) {
+        (self.min_output, self.max_output)
+    }
+
+    /// The exchange rate implied by the start of the auction, as an exact ratio of
+    /// `(output, input)` amounts reduced to lowest terms.
+    ///
+    /// This is the unrounded counterpart of [`starting_price`](Self::starting_price).
+    /// A description with a zero input amount has a rate of `(0, 1)`.
+    pub fn implied_rate(&self) -> (Amount, Amount) {
+        let (output, input) = (self.max_output.value(), self.input.amount.value());
+        if input == 0 {
+            return (Amount::zero(), 1u64.into());
+        }
+
+        let (mut a, mut b) = (output, input);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        ((output / a).into(), (input / a).into())
+    }
+
+    /// Check that the auction description is within the chain-wide limits
+    /// set by the auction component parameters.
+    pub fn validate_against_params(&self, params: &AuctionParameters) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.step_count <= params.max_step_count,
+            "the dutch auction step count MUST be at most {} (got: {})",
+            params.max_step_count,
+            self.step_count
+        );
+
+        Ok(())
+    }
+
+    /// The number of blocks between two price steps, or `None` if the
+    /// description has an invalid block window or step count.
+    fn step_size(&self) -> Option<u64> {
+        self.end_height
+            .checked_sub(self.start_height)?
+            .checked_div(self.step_count)
+            .filter(|step_size| *step_size > 0)
+    }
+
+    /// Returns whether the supplied height is one of the auction's trigger heights,
+    /// i.e. a height in `(start_height, end_height]` at which the price steps down.
+    pub fn is_trigger_height(&self, height: u64) -> bool {
+        let Some(step_size) = self.step_size() else {
+            return false;
+        };
+
+        height > self.start_height
+            && height <= self.end_height
+            && (height - self.start_height) % step_size == 0
+    }
+
+    /// The heights at which the chain steps the auction, in increasing order.
+    ///
+    /// There are `step_count` trigger heights, the last one being the end height.
+    /// Returns an empty list if the description has an invalid block window.
+    pub fn trigger_heights(&self) -> Vec<u64> {
+        let Some(step_size) = self.step_size() else {
+            return Vec::new();
+        };
+
+        (1..=self.step_count)
+            .map(|step_index| self.start_height + step_index * step_size)
+            .collect()
+    }
+
+    /// The heights at which the state of the auction can change, in increasing order.
+    ///
+    /// These are the trigger heights: the chain deploys the first position one step after
+    /// the start height, replaces it at every following trigger height, and closes the
+    /// auction at the last one, which is the end height. A client watching the auction only
+    /// needs to poll at these heights.
+    pub fn poll_heights(&self) -> Vec<u64> {
+        let mut heights = self.trigger_heights();
+        if heights.last() != Some(&self.end_height) {
+            heights.push(self.end_height);
+        }
+        heights
+    }
+
+    /// The number of price steps that have occurred by the supplied height, i.e. the
+    /// number of trigger heights at or below it, clamped to `[0, step_count]`.
+    ///
+    /// Returns zero if the description has an invalid block window.
+    pub fn steps_elapsed(&self, height: u64) -> u64 {
+        let Some(step_size) = self.step_size() else {
+            return 0;
+        };
+
+        (height.saturating_sub(self.start_height) / step_size).min(self.step_count)
+    }
+
+    /// The output offered for the entire input at the specified step index.
+    ///
+    /// This linearly interpolates between `max_output` at step `0` and `min_output`
+    /// at step `step_count - 1`, rounding down. Step indices past the last step are
+    /// clamped to it.
+    pub fn output_at_step(&self, step_index: u64) -> Amount {
+        let Some(last_step) = self.step_count.checked_sub(1).filter(|s| *s > 0) else {
+            return self.max_output;
+        };
+        let step_index = step_index.min(last_step);
+
+        let max_output = self.max_output.value();
+        let min_output = self.min_output.value();
+        let target_output_scaled = max_output
+            .saturating_mul((last_step - step_index).into())
+            .saturating_add(min_output.saturating_mul(step_index.into()));
+
+        (target_output_scaled / u128::from(last_step)).into()
+    }
+
+    /// The price schedule of the auction, as pairs of the trigger height at which the chain
+    /// deploys a position and the price offered by that position.
+    ///
+    /// The chain deploys a position at each trigger height but the last, which closes the
+    /// auction: the first one is at step `1`, one step after the start height, and the last
+    /// one at step `step_count - 1`, where it offers the [`ending_price`](Self::ending_price).
+    /// Prices are expressed as the amount of output asset received per unit of input asset,
+    /// and are derived from [`output_at_step`](Self::output_at_step), rounding down. The
+    /// schedule is empty if the description has an invalid block window or step count.
+    pub fn price_schedule(&self) -> impl Iterator<Item = (u64, Amount)> + '_ {
+        let step_size = self.step_size().unwrap_or_default();
+        let step_count = if step_size > 0 { self.step_count } else { 0 };
+
+        (1..step_count).map(move |step_index| {
+            let height = self.start_height + step_index * step_size;
+            let price = price_per_unit_input(self.output_at_step(step_index), self.input.amount);
+            (height, price)
+        })
+    }
+
+    /// The amount by which the output offered for the entire input drops at each
+    /// step, computed as `(max_output - min_output) / (step_count - 1)` and rounded down.
+    ///
+    /// As in [`output_at_step`](Self::output_at_step), the output is interpolated over the
+    /// `step_count - 1` intervals between the first and the last step, so the actual drop
+    /// between two steps can exceed this by one unit of rounding. Returns zero for flat-price
+    /// auctions, and for auctions with fewer than two steps.
+    pub fn price_decrement_per_step(&self) -> Amount {
+        let Some(intervals) = self.step_count.checked_sub(1).filter(|i| *i > 0) else {
+            return Amount::zero();
+        };
+        let spread = self
+            .max_output
+            .value()
+            .saturating_sub(self.min_output.value());
+
+        (spread / u128::from(intervals)).into()
+    }
+}
+
+/// Check that the output asset of an auction is one of the supplied known assets.
+///
+/// This is an advisory, client-side check: the chain accepts auctions for any
+/// output asset, but an auction targeting an asset that nobody holds can never
+/// be filled.
+pub fn validate_output_registered(
+    description: &DutchAuctionDescription,
+    known_assets: &BTreeSet<asset::Id>,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        known_assets.contains(&description.output_id),
+        "the auction output asset {} is not a known asset",
+        description.output_id
+    );
+
+    Ok(())
+}
+
+/// Render a duration in seconds to the nearest unit that keeps it short, e.g. `"~2h 15m"`.
+fn approximate_duration(secs: u64) -> String {
+    let (hours, minutes) = (secs / 3600, secs % 3600 / 60);
+    if hours > 0 {
+        format!("~{hours}h {minutes}m")
+    } else if minutes > 0 {
+        format!("~{minutes}m")
+    } else {
+        format!("~{secs}s")
+    }
+}
+
+fn price_per_unit_input(output: Amount, input: Amount) -> Amount {
+    output
+        .value()
+        .checked_div(input.value())
+        .unwrap_or_default()
+        .into()
 }
 
 /* Protobuf impls */
@@ -175,6 +778,73 @@ pub struct DutchAuctionState {
     pub output_reserves: Amount,
 }
 
+impl DutchAuctionState {
+    /// Returns whether the auction currently controls a liquidity position on the DEX,
+    /// through which its input is being sold.
+    pub fn uses_plf(&self) -> bool {
+        self.current_position.is_some()
+    }
+
+    /// The phase of the auction, as encoded by its sequence number.
+    pub fn phase(&self) -> AuctionPhase {
+        AuctionPhase::from_sequence(self.sequence)
+    }
+
+    /// The sequence number of a withdraw action for the auction in its current state.
+    ///
+    /// This is `sequence + 1`, as required by
+    /// [`validate_withdraw_seq`](actions::withdraw::validate_withdraw_seq) and the chain.
+    /// An opened auction has to be ended before it can be withdrawn, which takes it to
+    /// sequence `1`, so its withdraw sequence number should be computed from the ended state.
+    pub fn next_withdraw_seq(&self) -> u64 {
+        self.sequence.saturating_add(1)
+    }
+}
+
+/// The phases of a Dutch auction, as encoded by the sequence number of its state.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum AuctionPhase {
+    /// The auction is scheduled or executing (sequence `0`).
+    Opened,
+    /// The auction was closed, and its reserves can be withdrawn (sequence `1`).
+    Closed,
+    /// The auction reserves were withdrawn (sequence `2` or more).
+    Withdrawn { seq: u64 },
+}
+
+impl AuctionPhase {
+    /// Decode the phase of an auction from its sequence number.
+    pub fn from_sequence(sequence: u64) -> AuctionPhase {
+        match sequence {
+            0 => AuctionPhase::Opened,
+            1 => AuctionPhase::Closed,
+            seq => AuctionPhase::Withdrawn { seq },
+        }
+    }
+
+    /// The sequence number encoding the phase.
+    pub fn sequence(&self) -> u64 {
+        match self {
+            AuctionPhase::Opened => 0,
+            AuctionPhase::Closed => 1,
+            AuctionPhase::Withdrawn { seq } => *seq,
+        }
+    }
+
+    /// Move to the `next` phase, failing if the auction can't transition to it.
+    ///
+    /// Every transition increments the sequence number by one: an opened auction
+    /// is closed, a closed auction is withdrawn, and a withdrawn auction can be
+    /// withdrawn again.
+    pub fn transition_to(self, next: AuctionPhase) -> anyhow::Result<AuctionPhase> {
+        anyhow::ensure!(
+            self.sequence().checked_add(1) == Some(next.sequence()),
+            "illegal auction transition from {self:?} to {next:?}"
+        );
+        Ok(next)
+    }
+}
+
 /* Protobuf impls for `DutchAuctionState` */
 impl DomainType for DutchAuctionState {
     type Proto = pb::DutchAuctionState;
@@ -212,3 +882,607 @@ impl TryFrom<pb::DutchAuctionState> for DutchAuctionState {
     }
 }
 /* ********************************** */
+
+/// An auction selling 100upenumbra for between 250 and 1000gm, over ten steps between
+/// heights 100 and 200, shared by the tests of the auction modules.
+#[cfg(test)]
+pub(crate) fn sample_description() -> DutchAuctionDescription {
+    let cache = asset::Cache::with_known_assets();
+    DutchAuctionDescription {
+        input: Value {
+            amount: 100u64.into(),
+            asset_id: cache.get_unit("upenumbra").unwrap().id(),
+        },
+        output_id: cache.get_unit("gm").unwrap().id(),
+        max_output: 1_000u64.into(),
+        min_output: 250u64.into(),
+        start_height: 100,
+        end_height: 200,
+        step_count: 10,
+        nonce: [0u8; 32],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    #[test]
+    fn proceeds_range_is_bounded_by_outputs() {
+        let (worst_case, best_case) = sample_description().proceeds_range();
+        assert_eq!(worst_case, Amount::from(250u64));
+        assert_eq!(best_case, Amount::from(1_000u64));
+    }
+
+    #[test]
+    fn random_nonces_give_distinct_ids() {
+        let template = sample_description();
+        let new = || {
+            DutchAuctionDescription::new_with_random_nonce(
+                template.input,
+                template.output_id,
+                template.max_output,
+                template.min_output,
+                template.start_height,
+                template.end_height,
+                template.step_count,
+                &mut OsRng,
+            )
+        };
+
+        let (first, second) = (new().unwrap(), new().unwrap());
+        assert_ne!(first.nonce, second.nonce);
+        assert_ne!(first.id(), second.id());
+
+        // The description is checked before being returned.
+        assert!(DutchAuctionDescription::new_with_random_nonce(
+            template.input,
+            template.output_id,
+            template.min_output,
+            template.max_output,
+            template.start_height,
+            template.end_height,
+            template.step_count,
+            &mut OsRng,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn auction_id_known_answer() {
+        let description = DutchAuctionDescription {
+            input: Value {
+                amount: 100u64.into(),
+                asset_id: asset::Id::try_from([1u8; 32]).unwrap(),
+            },
+            output_id: asset::Id::try_from([2u8; 32]).unwrap(),
+            max_output: 1_000u64.into(),
+            min_output: 250u64.into(),
+            start_height: 100,
+            end_height: 200,
+            step_count: 10,
+            nonce: [7u8; 32],
+        };
+
+        // Changing this value changes the ids of every auction on chain.
+        assert_eq!(
+            hex::encode(description.id().0),
+            "9bbd3b7e34b6054f4d84399f9d2d13dce8896599cd23de41d23e53367501bfbb"
+        );
+
+        let renonced = DutchAuctionDescription {
+            nonce: [8u8; 32],
+            ..description
+        };
+        assert_ne!(renonced.id(), description.id());
+    }
+
+    #[test]
+    fn well_formed_descriptions() {
+        let description = sample_description();
+        assert!(description.check_well_formed().is_ok());
+
+        let check = |description: DutchAuctionDescription| {
+            description.check_well_formed().unwrap_err().to_string()
+        };
+        let reversed_heights = DutchAuctionDescription {
+            end_height: 100,
+            ..description.clone()
+        };
+        assert!(check(reversed_heights).contains("end height"));
+        let no_steps = DutchAuctionDescription {
+            step_count: 0,
+            ..description.clone()
+        };
+        assert!(check(no_steps).contains("step count MUST be positive"));
+        let uneven_steps = DutchAuctionDescription {
+            step_count: 7,
+            ..description.clone()
+        };
+        assert!(check(uneven_steps).contains("multiple of the step count"));
+        let inverted_outputs = DutchAuctionDescription {
+            min_output: 2_000u64.into(),
+            ..description.clone()
+        };
+        assert!(check(inverted_outputs).contains("max output"));
+        let flat_outputs = DutchAuctionDescription {
+            min_output: description.max_output,
+            ..description.clone()
+        };
+        assert!(check(flat_outputs).contains("max output"));
+        let single_step = DutchAuctionDescription {
+            step_count: 1,
+            ..description.clone()
+        };
+        assert!(check(single_step).contains("between 2 and"));
+        let same_assets = DutchAuctionDescription {
+            output_id: description.input.asset_id,
+            ..description.clone()
+        };
+        assert!(check(same_assets).contains("different from the output id"));
+        let oversized = DutchAuctionDescription {
+            max_output: (MAX_AUCTION_AMOUNT_RESERVES + 1).into(),
+            ..description
+        };
+        assert!(check(oversized).contains("52 bits"));
+    }
+
+    #[test]
+    fn auction_window_avoids_upgrade() {
+        // The auction runs from height 100 to 200.
+        let description = sample_description();
+        assert!(description.validate_against_upgrade(None).is_ok());
+        // The window ends before the upgrade...
+        assert!(description.validate_against_upgrade(Some(201)).is_ok());
+        // ...or starts after it.
+        assert!(description.validate_against_upgrade(Some(99)).is_ok());
+        for upgrade_height in [100, 150, 200] {
+            assert!(description
+                .validate_against_upgrade(Some(upgrade_height))
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn asset_id_accessors() {
+        let cache = asset::Cache::with_known_assets();
+        let description = sample_description();
+
+        assert_eq!(
+            description.input_asset_id(),
+            cache.get_unit("upenumbra").unwrap().id()
+        );
+        assert_eq!(
+            description.output_asset_id(),
+            cache.get_unit("gm").unwrap().id()
+        );
+    }
+
+    #[test]
+    fn starting_and_ending_prices() {
+        let description = sample_description();
+        // 1000 output for 100 input.
+        assert_eq!(description.starting_price(), Amount::from(10u64));
+        // 250 output for 100 input is 2.5, which rounds down.
+        assert_eq!(description.ending_price(), Amount::from(2u64));
+    }
+
+    #[test]
+    fn description_from_user_inputs() {
+        let template = sample_description();
+        let from_prices = |start_price: u64, end_price: u64| {
+            DutchAuctionDescription::from_user_inputs(
+                template.input,
+                template.output_id,
+                start_price.into(),
+                end_price.into(),
+                template.start_height,
+                template.end_height,
+                template.step_count,
+                &mut OsRng,
+            )
+        };
+
+        let description = from_prices(10, 2).unwrap();
+        assert_eq!(description.max_output, Amount::from(1_000u64));
+        assert_eq!(description.min_output, Amount::from(200u64));
+        assert_eq!(description.starting_price(), Amount::from(10u64));
+        assert_eq!(description.ending_price(), Amount::from(2u64));
+        assert!(description
+            .validate_against_params(&Default::default())
+            .is_ok());
+        // Each description gets a fresh nonce.
+        assert_ne!(description.id(), from_prices(10, 2).unwrap().id());
+
+        // Prices must decrease, and the outputs must fit in the reserves.
+        assert!(from_prices(2, 10).is_err());
+        assert!(from_prices(10, 0).is_err());
+        assert!(from_prices(u64::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn poll_heights_cover_the_schedule() {
+        let description = sample_description();
+        assert_eq!(description.poll_heights(), description.trigger_heights());
+        assert_eq!(description.poll_heights().len(), 10);
+        assert_eq!(description.poll_heights().first(), Some(&110));
+        assert_eq!(description.poll_heights().last(), Some(&200));
+
+        // An invalid block window has no trigger heights, only the end height.
+        let mut invalid = description;
+        invalid.step_count = 0;
+        assert_eq!(invalid.poll_heights(), vec![200]);
+    }
+
+    #[test]
+    fn steps_elapsed() {
+        // Steps every 10 blocks, from height 100 to 200.
+        let description = sample_description();
+
+        assert_eq!(description.steps_elapsed(0), 0);
+        assert_eq!(description.steps_elapsed(100), 0);
+        assert_eq!(description.steps_elapsed(109), 0);
+        assert_eq!(description.steps_elapsed(110), 1);
+        assert_eq!(description.steps_elapsed(150), 5);
+        assert_eq!(description.steps_elapsed(200), 10);
+        assert_eq!(description.steps_elapsed(1_000), 10);
+    }
+
+    #[test]
+    fn price_schedule() {
+        let description = sample_description();
+        let schedule: Vec<_> = description.price_schedule().collect();
+
+        // The chain deploys a position at the first 9 of the 10 trigger heights.
+        assert_eq!(schedule.len(), 9);
+        assert_eq!(schedule[8], (190, description.ending_price()));
+        // (8 * 1000 + 1 * 250) / 9 = 916, then divided by an input of 100.
+        assert_eq!(schedule[0], (110, 9u64.into()));
+        assert!(schedule.windows(2).all(|steps| steps[0].1 >= steps[1].1));
+
+        // The schedule matches the positions of the simulated auction.
+        let simulated = super::simulation::simulate_auction(&description, 0).price_schedule;
+        assert_eq!(schedule.len(), simulated.len());
+        for ((height, price), (simulated_height, output)) in schedule.iter().zip(simulated) {
+            assert_eq!(*height, simulated_height);
+            assert_eq!(
+                *price,
+                price_per_unit_input(output, description.input.amount)
+            );
+        }
+
+        let no_steps = DutchAuctionDescription {
+            step_count: 0,
+            ..description
+        };
+        assert_eq!(no_steps.price_schedule().count(), 0);
+    }
+
+    #[test]
+    fn implied_rate() {
+        let description = DutchAuctionDescription {
+            input: Value {
+                amount: 1_500u64.into(),
+                asset_id: asset::Id::try_from([1u8; 32]).unwrap(),
+            },
+            max_output: 20_000u64.into(),
+            ..sample_description()
+        };
+        // 20000 / 1500 = 40 / 3, while the starting price rounds down to 13.
+        assert_eq!(
+            description.implied_rate(),
+            (Amount::from(40u64), Amount::from(3u64))
+        );
+        assert_eq!(description.starting_price(), Amount::from(13u64));
+
+        let no_input = DutchAuctionDescription {
+            input: Value {
+                amount: Amount::zero(),
+                ..description.input
+            },
+            ..description
+        };
+        assert_eq!(
+            no_input.implied_rate(),
+            (Amount::zero(), Amount::from(1u64))
+        );
+    }
+
+    #[test]
+    fn price_decrement_per_step() {
+        let mut description = sample_description();
+        description.max_output = 1_000u64.into();
+        description.min_output = 250u64.into();
+        description.step_count = 10;
+        // (1000 - 250) / 9, rounded down.
+        let decrement = description.price_decrement_per_step();
+        assert_eq!(decrement, 83u64.into());
+        for step in 0..9 {
+            let drop = description.output_at_step(step) - description.output_at_step(step + 1);
+            assert!(drop == decrement || drop == decrement + 1u64.into());
+        }
+
+        description.step_count = 1;
+        assert_eq!(description.price_decrement_per_step(), Amount::zero());
+
+        description.step_count = 10;
+        description.min_output = description.max_output;
+        assert_eq!(description.price_decrement_per_step(), Amount::zero());
+    }
+
+    #[test]
+    fn step_count_is_bounded_by_params() {
+        let params = AuctionParameters { max_step_count: 10 };
+
+        let mut description = sample_description();
+        assert!(description.validate_against_params(&params).is_ok());
+
+        description.step_count = 20;
+        assert!(description.validate_against_params(&params).is_err());
+    }
+
+    #[test]
+    fn step_count_is_bounded_by_default_params() {
+        let params = AuctionParameters::default();
+
+        let mut description = sample_description();
+        description.step_count = MAX_AUCTION_STEP_COUNT;
+        assert!(description.validate_against_params(&params).is_ok());
+
+        description.step_count = MAX_AUCTION_STEP_COUNT + 1;
+        assert!(description.validate_against_params(&params).is_err());
+    }
+
+    #[test]
+    fn output_asset_must_be_known() {
+        let cache = asset::Cache::with_known_assets();
+        let description = sample_description();
+
+        let mut known_assets = BTreeSet::from([description.input.asset_id]);
+        assert!(validate_output_registered(&description, &known_assets).is_err());
+
+        known_assets.insert(cache.get_unit("gm").unwrap().id());
+        assert!(validate_output_registered(&description, &known_assets).is_ok());
+    }
+
+    fn sample_auction(next_trigger: u64) -> DutchAuction {
+        let description = sample_description();
+        DutchAuction {
+            state: DutchAuctionState {
+                sequence: 0,
+                current_position: None,
+                next_trigger: NonZeroU64::new(next_trigger),
+                input_reserves: description.input.amount,
+                output_reserves: Amount::zero(),
+            },
+            description,
+        }
+    }
+
+    #[test]
+    fn accumulated_output_value() {
+        let mut auction = sample_auction(110);
+        assert_eq!(auction.output_value(), None);
+
+        auction.state.output_reserves = 300u64.into();
+        assert_eq!(
+            auction.output_value(),
+            Some(Value {
+                amount: 300u64.into(),
+                asset_id: auction.description.output_id,
+            })
+        );
+    }
+
+    #[test]
+    fn average_realized_price() {
+        let mut auction = sample_auction(110);
+        assert_eq!(auction.average_realized_price(), None);
+
+        // Half of the 100 input was sold for 450 output.
+        auction.state.input_reserves = 50u64.into();
+        auction.state.output_reserves = 450u64.into();
+        assert_eq!(auction.average_realized_price(), Some(9u64.into()));
+
+        // All of the input was sold for 700 output.
+        auction.state.sequence = 1;
+        auction.state.input_reserves = Amount::zero();
+        auction.state.output_reserves = 700u64.into();
+        assert_eq!(auction.average_realized_price(), Some(7u64.into()));
+
+        // The fills can't be recovered once the auction is withdrawn.
+        auction.state.sequence = 2;
+        auction.state.output_reserves = Amount::zero();
+        assert_eq!(auction.average_realized_price(), None);
+    }
+
+    #[test]
+    fn auction_phases() {
+        let phase = |sequence| {
+            DutchAuctionState {
+                sequence,
+                current_position: None,
+                next_trigger: None,
+                input_reserves: Amount::zero(),
+                output_reserves: Amount::zero(),
+            }
+            .phase()
+        };
+        assert_eq!(phase(0), AuctionPhase::Opened);
+        assert_eq!(phase(1), AuctionPhase::Closed);
+        assert_eq!(phase(2), AuctionPhase::Withdrawn { seq: 2 });
+        assert_eq!(phase(100), AuctionPhase::Withdrawn { seq: 100 });
+        for sequence in [0, 1, 2, 100] {
+            assert_eq!(phase(sequence).sequence(), sequence);
+        }
+
+        let withdrawn = AuctionPhase::Withdrawn { seq: 2 };
+        assert!(AuctionPhase::Opened
+            .transition_to(AuctionPhase::Closed)
+            .is_ok());
+        assert!(AuctionPhase::Closed.transition_to(withdrawn).is_ok());
+        assert!(withdrawn
+            .transition_to(AuctionPhase::Withdrawn { seq: 3 })
+            .is_ok());
+        assert!(withdrawn.transition_to(AuctionPhase::Opened).is_err());
+        assert!(AuctionPhase::Opened.transition_to(withdrawn).is_err());
+    }
+
+    #[test]
+    fn next_withdraw_seq() {
+        let next = |sequence| {
+            DutchAuctionState {
+                sequence,
+                current_position: None,
+                next_trigger: None,
+                input_reserves: Amount::zero(),
+                output_reserves: Amount::zero(),
+            }
+            .next_withdraw_seq()
+        };
+        assert_eq!(next(0), 1);
+        assert_eq!(next(1), 2);
+        assert_eq!(next(2), 3);
+        assert_eq!(next(3), 4);
+    }
+
+    #[test]
+    fn countdown_for_each_phase() {
+        // The auction runs from height 100 to 200.
+        let mut auction = sample_auction(110);
+
+        assert_eq!(auction.countdown(10, 60), "starts in ~1h 30m");
+        assert_eq!(auction.countdown(99, 5), "starts in ~5s");
+        assert_eq!(auction.countdown(100, 81), "~2h 15m");
+        assert_eq!(auction.countdown(190, 5), "~50s");
+        assert_eq!(auction.countdown(188, 5), "~1m");
+        assert_eq!(auction.countdown(200, 5), "ended");
+
+        // A closed auction has ended, regardless of its end height.
+        auction.state.sequence = 1;
+        assert_eq!(auction.countdown(150, 5), "ended");
+    }
+
+    #[test]
+    fn withdrawable_value() {
+        let mut auction = sample_auction(110);
+        auction.state.input_reserves = 40u64.into();
+        auction.state.output_reserves = 600u64.into();
+        // An active auction can't be withdrawn.
+        assert!(auction.withdrawable_value().is_empty());
+
+        auction.state.sequence = 1;
+        assert_eq!(
+            auction.withdrawable_value(),
+            BTreeMap::from([
+                (auction.description.input.asset_id, Amount::from(40u64)),
+                (auction.description.output_id, Amount::from(600u64)),
+            ])
+        );
+
+        auction.state.input_reserves = Amount::zero();
+        assert_eq!(auction.withdrawable_value().len(), 1);
+    }
+
+    #[test]
+    fn auction_batch_round_trip() {
+        let batch = DutchAuctionBatch {
+            auctions: (0u8..3)
+                .map(|nonce| {
+                    let mut auction = sample_auction(110);
+                    auction.description.nonce = [nonce; 32];
+                    auction.state.sequence = u64::from(nonce);
+                    auction
+                })
+                .collect(),
+        };
+
+        let encoded = batch.encode_to_vec();
+        assert_eq!(
+            DutchAuctionBatch::decode(encoded.as_slice()).unwrap(),
+            batch
+        );
+
+        let json = serde_json::to_string(&batch).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DutchAuctionBatch>(&json).unwrap(),
+            batch
+        );
+    }
+
+    #[test]
+    fn auction_controls_position() {
+        let mut auction = sample_auction(110);
+        assert!(!auction.state.uses_plf());
+        auction.state.current_position = Some(position::Id([1u8; 32]));
+        assert!(auction.state.uses_plf());
+    }
+
+    #[test]
+    fn unfilled_auctions() {
+        let mut unfilled = sample_auction(0);
+        unfilled.state.sequence = 1;
+        assert!(unfilled.ended_unfilled());
+
+        let mut partially_filled = unfilled.clone();
+        partially_filled.state.input_reserves = 40u64.into();
+        partially_filled.state.output_reserves = 600u64.into();
+        assert!(!partially_filled.ended_unfilled());
+
+        // An auction that is still open hasn't ended.
+        assert!(!sample_auction(120).ended_unfilled());
+    }
+
+    #[test]
+    fn auctions_sort_by_end_height() {
+        let ends_late = sample_auction(120);
+        let mut ends_early = sample_auction(120);
+        ends_early.description.end_height = 150;
+
+        let mut auctions = vec![ends_late.clone(), ends_early.clone()];
+        auctions.sort_by_key(DutchAuction::sort_key_by_end);
+
+        assert_eq!(auctions, vec![ends_early, ends_late]);
+    }
+
+    #[test]
+    fn debug_map_is_flat() {
+        let map = sample_auction(120).to_debug_map();
+
+        assert_eq!(map.len(), 15);
+        assert_eq!(map["description.step_count"], "10");
+        assert_eq!(map["state.next_trigger"], "120");
+        assert_eq!(map["state.current_position"], "none");
+        assert!(map.contains_key("id"));
+        assert!(map.contains_key("description.nonce"));
+    }
+
+    #[test]
+    fn blocks_until_next_step() {
+        // Mid-step: the next trigger is at height 120.
+        assert_eq!(sample_auction(120).blocks_until_next_step(115), Some(5));
+        // On a trigger boundary, the auction has already been stepped and is
+        // waiting for the following trigger.
+        assert_eq!(sample_auction(130).blocks_until_next_step(120), Some(10));
+        // A trigger that is not on the schedule is rejected.
+        assert_eq!(sample_auction(125).blocks_until_next_step(120), None);
+        // Inactive auctions don't step.
+        let mut closed = sample_auction(130);
+        closed.state.sequence = 1;
+        assert_eq!(closed.blocks_until_next_step(120), None);
+    }
+
+    #[test]
+    fn missed_trigger_is_stuck() {
+        let auction = sample_auction(120);
+        assert!(!auction.is_stuck(115));
+        // The trigger at height 120 was missed: the state still points to it.
+        assert!(auction.is_stuck(120));
+        assert!(auction.is_stuck(125));
+
+        let mut closed = auction;
+        closed.state.sequence = 1;
+        assert!(!closed.is_stuck(125));
+    }
+}
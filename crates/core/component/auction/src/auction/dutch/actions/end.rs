@@ -1,10 +1,11 @@
 use anyhow::anyhow;
 use penumbra_sdk_asset::{Balance, Value};
+use penumbra_sdk_dex::lp::position;
 use penumbra_sdk_proto::{core::component::auction::v1 as pb, DomainType};
 use penumbra_sdk_txhash::{EffectHash, EffectingData};
 use serde::{Deserialize, Serialize};
 
-use crate::auction::{id::AuctionId, AuctionNft};
+use crate::auction::{dutch::DutchAuctionState, id::AuctionId, AuctionNft};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(
@@ -40,6 +41,19 @@ impl ActionDutchAuctionEnd {
     }
 }
 
+/// The liquidity positions that the chain closes and withdraws when ending
+/// an auction with the supplied state.
+///
+/// Only an opened auction controls a position: ending an auction that is
+/// already closed or withdrawn is a no-op, and retires nothing.
+pub fn positions_retired_on_end(state: &DutchAuctionState) -> Vec<position::Id> {
+    if state.sequence != 0 {
+        return Vec::new();
+    }
+
+    state.current_position.into_iter().collect()
+}
+
 /* Effect hash */
 impl EffectingData for ActionDutchAuctionEnd {
     fn effect_hash(&self) -> EffectHash {
@@ -71,3 +85,29 @@ impl TryFrom<pb::ActionDutchAuctionEnd> for ActionDutchAuctionEnd {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use penumbra_sdk_num::Amount;
+
+    fn state(current_position: Option<position::Id>) -> DutchAuctionState {
+        DutchAuctionState {
+            sequence: 0,
+            current_position,
+            next_trigger: None,
+            input_reserves: Amount::zero(),
+            output_reserves: Amount::zero(),
+        }
+    }
+
+    #[test]
+    fn retired_positions() {
+        let position_id = position::Id([1u8; 32]);
+        assert_eq!(
+            positions_retired_on_end(&state(Some(position_id))),
+            vec![position_id]
+        );
+        assert!(positions_retired_on_end(&state(None)).is_empty());
+    }
+}
@@ -1,11 +1,11 @@
 pub mod schedule;
-pub use schedule::ActionDutchAuctionSchedule;
+pub use schedule::{can_afford_schedule, ActionDutchAuctionSchedule};
 
 pub mod end;
-pub use end::ActionDutchAuctionEnd;
+pub use end::{positions_retired_on_end, ActionDutchAuctionEnd};
 
 pub mod withdraw;
-pub use withdraw::ActionDutchAuctionWithdraw;
+pub use withdraw::{validate_withdraw_seq, ActionDutchAuctionWithdraw};
 
 pub mod plan;
 pub use plan::ActionDutchAuctionWithdrawPlan;
@@ -27,6 +27,24 @@ impl ActionDutchAuctionWithdrawPlan {
         }
     }
 
+    /// A key identifying this withdrawal, stable across retries of the same plan.
+    ///
+    /// The key only depends on the auction id and the withdrawal sequence number,
+    /// since a given auction can be withdrawn at most once for each sequence number.
+    pub fn idempotency_key(&self) -> [u8; 32] {
+        let hash = blake2b_simd::Params::default()
+            .personal(b"penumbra_DA_wdr")
+            .hash_length(32)
+            .to_state()
+            .update(&self.auction_id.0)
+            .update(&self.seq.to_le_bytes())
+            .finalize();
+
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(hash.as_bytes());
+        bytes
+    }
+
     pub fn reserves_balance(&self) -> Balance {
         Balance::from(self.reserves_input) + Balance::from(self.reserves_output)
     }
@@ -98,3 +116,31 @@ impl TryFrom<pb::ActionDutchAuctionWithdrawPlan> for ActionDutchAuctionWithdrawP
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use penumbra_sdk_asset::asset;
+
+    fn plan(seq: u64) -> ActionDutchAuctionWithdrawPlan {
+        let cache = asset::Cache::with_known_assets();
+        ActionDutchAuctionWithdrawPlan {
+            auction_id: AuctionId([1u8; 32]),
+            seq,
+            reserves_input: Value {
+                amount: 100u64.into(),
+                asset_id: cache.get_unit("upenumbra").unwrap().id(),
+            },
+            reserves_output: Value {
+                amount: 50u64.into(),
+                asset_id: cache.get_unit("gm").unwrap().id(),
+            },
+        }
+    }
+
+    #[test]
+    fn idempotency_key_depends_on_seq() {
+        assert_eq!(plan(2).idempotency_key(), plan(2).idempotency_key());
+        assert_ne!(plan(2).idempotency_key(), plan(3).idempotency_key());
+    }
+}
@@ -1,9 +1,11 @@
 use crate::auction::{dutch::DutchAuctionDescription, nft::AuctionNft};
 use anyhow::anyhow;
-use penumbra_sdk_asset::{Balance, Value};
+use penumbra_sdk_asset::{asset, Balance, Value};
+use penumbra_sdk_num::Amount;
 use penumbra_sdk_proto::{core::component::auction::v1 as pb, DomainType};
 use penumbra_sdk_txhash::{EffectHash, EffectingData};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// The maximum amount of input/output in a Dutch auction description.
 /// 52 bits gives us enough headroom to do infaillible price interpolation.
@@ -42,6 +44,21 @@ impl ActionDutchAuctionSchedule {
     }
 }
 
+/// Check whether the `available` balance of a wallet holds enough of the input asset
+/// to fund the scheduled auction.
+///
+/// This is a client-side check, meant to catch schedule actions that would leave the
+/// transaction unbalanced before planning it.
+pub fn can_afford_schedule(
+    action: &ActionDutchAuctionSchedule,
+    available: &BTreeMap<asset::Id, Amount>,
+) -> bool {
+    let input = action.description.input;
+    available
+        .get(&input.asset_id)
+        .is_some_and(|amount| *amount >= input.amount)
+}
+
 /* Effect hash */
 impl EffectingData for ActionDutchAuctionSchedule {
     fn effect_hash(&self) -> EffectHash {
@@ -76,3 +93,49 @@ impl TryFrom<pb::ActionDutchAuctionSchedule> for ActionDutchAuctionSchedule {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auction::dutch::sample_description;
+
+    #[test]
+    fn schedule_balance_locks_input() {
+        let description = sample_description();
+        let schedule = ActionDutchAuctionSchedule {
+            description: description.clone(),
+        };
+        let balance = schedule.balance();
+
+        // The input value is consumed by the auction...
+        assert_eq!(
+            balance.required().collect::<Vec<_>>(),
+            vec![description.input]
+        );
+        // ...in exchange for the opened auction NFT.
+        assert_eq!(
+            balance.provided().collect::<Vec<_>>(),
+            vec![Value {
+                amount: 1u64.into(),
+                asset_id: AuctionNft::new(description.id(), 0).asset_id(),
+            }]
+        );
+    }
+
+    #[test]
+    fn affordability_depends_on_input_balance() {
+        let schedule = ActionDutchAuctionSchedule {
+            description: sample_description(),
+        };
+        let upenumbra = schedule.description.input.asset_id;
+        let gm = schedule.description.output_id;
+
+        let balance = |amount: u64| BTreeMap::from([(upenumbra, Amount::from(amount))]);
+        assert!(can_afford_schedule(&schedule, &balance(100)));
+        assert!(can_afford_schedule(&schedule, &balance(250)));
+        assert!(!can_afford_schedule(&schedule, &balance(99)));
+        // Holding the output asset doesn't help.
+        let only_output = BTreeMap::from([(gm, Amount::from(1_000u64))]);
+        assert!(!can_afford_schedule(&schedule, &only_output));
+    }
+}
@@ -2,11 +2,13 @@ use crate::auction::{
     dutch::{
         actions::{ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw},
         asset::Metadata,
+        DutchAuction,
     },
     id::AuctionId,
 };
 use anyhow::anyhow;
-use penumbra_sdk_asset::ValueView;
+use penumbra_sdk_asset::{asset, ValueView};
+use penumbra_sdk_num::Amount;
 use penumbra_sdk_proto::{core::component::auction::v1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
 
@@ -35,6 +37,134 @@ pub struct ActionDutchAuctionWithdrawView {
     pub reserves: Vec<ValueView>,
 }
 
+/// A rendering of an [`ActionDutchAuctionScheduleView`] for frontends, with the
+/// amounts pre-formatted in the display unit of their asset.
+///
+/// Amounts of assets without metadata are rendered in their base unit, followed
+/// by the asset id.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleViewDisplay {
+    pub auction_id: String,
+    pub input: String,
+    pub max_output: String,
+    pub min_output: String,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub step_count: u64,
+}
+
+fn format_amount(amount: Amount, asset_id: asset::Id, metadata: Option<&Metadata>) -> String {
+    match metadata {
+        Some(metadata) => {
+            let unit = metadata.default_unit();
+            format!("{}{}", unit.format_value(amount), unit)
+        }
+        None => format!("{amount}{asset_id}"),
+    }
+}
+
+impl From<&ActionDutchAuctionScheduleView> for ScheduleViewDisplay {
+    fn from(view: &ActionDutchAuctionScheduleView) -> Self {
+        let description = &view.action.description;
+        let input_metadata = view.input_metadata.as_ref();
+        let output_metadata = view.output_metadata.as_ref();
+
+        ScheduleViewDisplay {
+            auction_id: view.auction_id.to_string(),
+            input: format_amount(
+                description.input.amount,
+                description.input.asset_id,
+                input_metadata,
+            ),
+            max_output: format_amount(
+                description.max_output,
+                description.output_id,
+                output_metadata,
+            ),
+            min_output: format_amount(
+                description.min_output,
+                description.output_id,
+                output_metadata,
+            ),
+            start_height: description.start_height,
+            end_height: description.end_height,
+            step_count: description.step_count,
+        }
+    }
+}
+
+/// A [`DutchAuction`] together with the metadata of its input and output assets,
+/// for rendering the full state of the auction to users.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DutchAuctionFullView {
+    pub auction: DutchAuction,
+    pub input_metadata: Option<Metadata>,
+    pub output_metadata: Option<Metadata>,
+}
+
+impl DutchAuctionFullView {
+    /// Describe the auction in human terms, as of the supplied block height.
+    ///
+    /// While the auction is active, its input is held by a liquidity position, so the
+    /// description reports the output currently offered for the full input. The first
+    /// position is deployed at step `1`, so that is the offer reported until it is.
+    /// Once the auction is closed, it reports how much of the input was sold, and for what.
+    pub fn describe(&self, height: u64) -> String {
+        let DutchAuction { description, state } = &self.auction;
+        let input_metadata = self.input_metadata.as_ref();
+        let output_metadata = self.output_metadata.as_ref();
+
+        let input = format_amount(
+            description.input.amount,
+            description.input.asset_id,
+            input_metadata,
+        );
+        let output = match output_metadata {
+            Some(metadata) => metadata.default_unit().to_string(),
+            None => description.output_id.to_string(),
+        };
+
+        match state.sequence {
+            0 => {
+                let step = description.steps_elapsed(height).max(1);
+                let offered = format_amount(
+                    description.output_at_step(step),
+                    description.output_id,
+                    output_metadata,
+                );
+                if height < description.start_height {
+                    format!(
+                        "Selling {input} for {output} from height {}, starting at {offered} for the full input",
+                        description.start_height
+                    )
+                } else {
+                    format!(
+                        "Selling {input} for {output}, currently offering {offered} for the full input (step {step} of {})",
+                        description.step_count
+                    )
+                }
+            }
+            1 => {
+                let sold = format_amount(
+                    description
+                        .input
+                        .amount
+                        .saturating_sub(&state.input_reserves),
+                    description.input.asset_id,
+                    input_metadata,
+                );
+                let proceeds = format_amount(
+                    state.output_reserves,
+                    description.output_id,
+                    output_metadata,
+                );
+                format!("Closed: {sold} of {input} sold for {proceeds}, ready to withdraw")
+            }
+            _ => format!("Withdrawn: the sale of {input} for {output} has ended"),
+        }
+    }
+}
+
 /* Conversion back to an action */
 
 impl From<ActionDutchAuctionScheduleView> for ActionDutchAuctionSchedule {
@@ -130,3 +260,117 @@ impl TryFrom<pb::ActionDutchAuctionWithdrawView> for ActionDutchAuctionWithdrawV
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auction::dutch::{DutchAuctionDescription, DutchAuctionState};
+    use penumbra_sdk_asset::Value;
+    use penumbra_sdk_dex::lp::position;
+
+    #[test]
+    fn schedule_view_display_formats_amounts() {
+        let cache = asset::Cache::with_known_assets();
+        // Both denominations have a display unit with 6 decimals.
+        let penumbra = cache.get_unit("upenumbra").unwrap().base();
+        let gm = cache.get_unit("ugm").unwrap().base();
+
+        let description = DutchAuctionDescription {
+            input: Value {
+                amount: 1_500_000u64.into(),
+                asset_id: penumbra.id(),
+            },
+            output_id: gm.id(),
+            max_output: 20_000_000u64.into(),
+            min_output: 250_000u64.into(),
+            start_height: 100,
+            end_height: 200,
+            step_count: 10,
+            nonce: [0u8; 32],
+        };
+        let auction_id = description.id();
+        let mut view = ActionDutchAuctionScheduleView {
+            action: ActionDutchAuctionSchedule { description },
+            auction_id,
+            input_metadata: Some(penumbra.clone()),
+            output_metadata: Some(gm),
+        };
+
+        let display = ScheduleViewDisplay::from(&view);
+        assert_eq!(display.auction_id, auction_id.to_string());
+        assert_eq!(display.input, "1.5penumbra");
+        assert_eq!(display.max_output, "20gm");
+        assert_eq!(display.min_output, "0.25gm");
+
+        let json = serde_json::to_value(&display).unwrap();
+        assert_eq!(json["input"], "1.5penumbra");
+        assert_eq!(json["step_count"], 10);
+
+        // Without metadata, amounts are rendered in base units.
+        view.input_metadata = None;
+        let display = ScheduleViewDisplay::from(&view);
+        assert_eq!(display.input, format!("1500000{}", penumbra.id()));
+    }
+
+    #[test]
+    fn full_view_describes_auction() {
+        let cache = asset::Cache::with_known_assets();
+        let penumbra = cache.get_unit("upenumbra").unwrap().base();
+        let gm = cache.get_unit("ugm").unwrap().base();
+
+        let description = DutchAuctionDescription {
+            input: Value {
+                amount: 100_000_000u64.into(),
+                asset_id: penumbra.id(),
+            },
+            output_id: gm.id(),
+            max_output: 1_000_000_000u64.into(),
+            min_output: 100_000_000u64.into(),
+            start_height: 100,
+            end_height: 200,
+            step_count: 10,
+            nonce: [0u8; 32],
+        };
+        let mut view = DutchAuctionFullView {
+            auction: DutchAuction {
+                description,
+                state: DutchAuctionState {
+                    sequence: 0,
+                    current_position: Some(position::Id([1u8; 32])),
+                    next_trigger: std::num::NonZeroU64::new(160),
+                    input_reserves: Amount::zero(),
+                    output_reserves: Amount::zero(),
+                },
+            },
+            input_metadata: Some(penumbra),
+            output_metadata: Some(gm),
+        };
+
+        // Halfway through: (4 * 1000 + 5 * 100) / 9 = 500.
+        assert_eq!(
+            view.describe(150),
+            "Selling 100penumbra for gm, currently offering 500gm for the full input (step 5 of 10)"
+        );
+        assert_eq!(
+            view.describe(50),
+            "Selling 100penumbra for gm from height 100, starting at 900gm for the full input"
+        );
+        // No position is deployed until the first trigger height, at step 1.
+        assert_eq!(
+            view.describe(105),
+            "Selling 100penumbra for gm, currently offering 900gm for the full input (step 1 of 10)"
+        );
+
+        view.auction.state = DutchAuctionState {
+            sequence: 1,
+            current_position: None,
+            next_trigger: None,
+            input_reserves: 40_000_000u64.into(),
+            output_reserves: 300_000_000u64.into(),
+        };
+        assert_eq!(
+            view.describe(150),
+            "Closed: 60penumbra of 100penumbra sold for 300gm, ready to withdraw"
+        );
+    }
+}
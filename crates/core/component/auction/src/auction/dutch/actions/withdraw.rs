@@ -1,5 +1,5 @@
-use crate::auction::{id::AuctionId, AuctionNft};
-use anyhow::anyhow;
+use crate::auction::{dutch::DutchAuctionState, id::AuctionId, AuctionNft};
+use anyhow::{anyhow, ensure};
 use ark_ff::Zero;
 use decaf377_rdsa::Fr;
 use penumbra_sdk_asset::{balance, Balance, Value};
@@ -54,6 +54,64 @@ impl ActionDutchAuctionWithdraw {
 
         self.reserves_commitment + next_auction_nft - prev_auction_nft
     }
+
+    /// Check that the supplied reserves open the reserves commitment of this action.
+    ///
+    /// The reserves commitment is transparent, i.e. it uses a zero blinding factor, so a
+    /// verifier can recompute it from the claimed reserves.
+    pub fn verify_opening(&self, input: Value, output: Value) -> bool {
+        let reserves_balance = Balance::from(input) + Balance::from(output);
+        reserves_balance.commit(Fr::zero()) == self.reserves_commitment
+    }
+
+    /// Compute the balance of this action, given the reserves being withdrawn.
+    ///
+    /// The action only carries a commitment to the reserves, so they must be supplied,
+    /// e.g. from the corresponding [`ActionDutchAuctionWithdrawPlan`]. This errors if
+    /// the supplied reserves don't match the reserves commitment.
+    ///
+    /// [`ActionDutchAuctionWithdrawPlan`]: super::ActionDutchAuctionWithdrawPlan
+    pub fn balance(
+        &self,
+        reserves_input: Value,
+        reserves_output: Value,
+    ) -> anyhow::Result<Balance> {
+        ensure!(
+            self.verify_opening(reserves_input, reserves_output),
+            "the supplied reserves do not match the reserves commitment of the withdraw action"
+        );
+        let reserves_balance = Balance::from(reserves_input) + Balance::from(reserves_output);
+
+        let prev_auction_nft = Balance::from(Value {
+            amount: 1u128.into(),
+            asset_id: AuctionNft::new(self.auction_id, self.seq.saturating_sub(1)).asset_id(),
+        });
+        let next_auction_nft = Balance::from(Value {
+            amount: 1u128.into(),
+            asset_id: AuctionNft::new(self.auction_id, self.seq).asset_id(),
+        });
+
+        Ok(reserves_balance + next_auction_nft - prev_auction_nft)
+    }
+}
+
+/// Check that the sequence number of a withdraw action is the next sequence
+/// number for the supplied auction state.
+///
+/// A withdrawal is only valid if `action.seq == state.sequence + 1`, this can
+/// be used by clients to catch an incorrect sequence number before broadcasting.
+pub fn validate_withdraw_seq(
+    action: &ActionDutchAuctionWithdraw,
+    state: &DutchAuctionState,
+) -> anyhow::Result<()> {
+    let expected_seq = state.sequence.saturating_add(1);
+    ensure!(
+        action.seq == expected_seq,
+        "the action sequence number MUST be incremented by one (previous: {}, action: {})",
+        state.sequence,
+        action.seq
+    );
+    Ok(())
 }
 
 /* Effect hash */
@@ -99,3 +157,94 @@ impl TryFrom<pb::ActionDutchAuctionWithdraw> for ActionDutchAuctionWithdraw {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auction::dutch::actions::ActionDutchAuctionWithdrawPlan;
+    use penumbra_sdk_asset::asset;
+    use penumbra_sdk_num::Amount;
+
+    fn withdraw_with_seq(seq: u64) -> ActionDutchAuctionWithdraw {
+        ActionDutchAuctionWithdraw {
+            auction_id: AuctionId([0u8; 32]),
+            seq,
+            reserves_commitment: Balance::zero().commit(Fr::zero()),
+        }
+    }
+
+    #[test]
+    fn withdraw_balance_matches_plan() {
+        let cache = asset::Cache::with_known_assets();
+        let plan = ActionDutchAuctionWithdrawPlan {
+            auction_id: AuctionId([1u8; 32]),
+            seq: 2,
+            reserves_input: Value {
+                amount: 40u64.into(),
+                asset_id: cache.get_unit("upenumbra").unwrap().id(),
+            },
+            reserves_output: Value {
+                amount: 600u64.into(),
+                asset_id: cache.get_unit("gm").unwrap().id(),
+            },
+        };
+        let action = plan.to_action();
+
+        let balance = action
+            .balance(plan.reserves_input, plan.reserves_output)
+            .unwrap();
+        assert_eq!(balance, plan.balance());
+        assert_eq!(balance.commit(Fr::zero()), action.balance_commitment());
+
+        // The reserves must match the commitment.
+        let wrong_output = Value {
+            amount: 601u64.into(),
+            ..plan.reserves_output
+        };
+        assert!(action.balance(plan.reserves_input, wrong_output).is_err());
+    }
+
+    #[test]
+    fn reserves_opening_is_verified() {
+        let cache = asset::Cache::with_known_assets();
+        let input = Value {
+            amount: 40u64.into(),
+            asset_id: cache.get_unit("upenumbra").unwrap().id(),
+        };
+        let output = Value {
+            amount: 600u64.into(),
+            asset_id: cache.get_unit("gm").unwrap().id(),
+        };
+        let action = ActionDutchAuctionWithdraw {
+            reserves_commitment: (Balance::from(input) + Balance::from(output)).commit(Fr::zero()),
+            ..withdraw_with_seq(2)
+        };
+
+        assert!(action.verify_opening(input, output));
+        // The order of the reserves doesn't matter.
+        assert!(action.verify_opening(output, input));
+        assert!(!action.verify_opening(
+            input,
+            Value {
+                amount: 599u64.into(),
+                ..output
+            }
+        ));
+        assert!(!action.verify_opening(input, input));
+    }
+
+    #[test]
+    fn withdraw_seq_must_follow_state() {
+        let closed = DutchAuctionState {
+            sequence: 1,
+            current_position: None,
+            next_trigger: None,
+            input_reserves: Amount::zero(),
+            output_reserves: Amount::zero(),
+        };
+
+        assert!(validate_withdraw_seq(&withdraw_with_seq(2), &closed).is_ok());
+        assert!(validate_withdraw_seq(&withdraw_with_seq(1), &closed).is_err());
+        assert!(validate_withdraw_seq(&withdraw_with_seq(3), &closed).is_err());
+    }
+}
@@ -0,0 +1,349 @@
+//! Client-side helpers that aggregate over collections of Dutch auctions.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use penumbra_sdk_asset::asset;
+use penumbra_sdk_dex::lp::position::{self, Position};
+use penumbra_sdk_num::Amount;
+
+use crate::auction::{
+    dutch::{DutchAuction, DutchAuctionDescription, DutchAuctionState},
+    AuctionId, AuctionNft,
+};
+
+/// Build an index mapping each supplied [`Position`] to the auction that controls it.
+///
+/// A position is considered auction-controlled if it is the `current_position` of
+/// one of the supplied auctions. Positions that are not controlled by any of the
+/// supplied auctions are omitted from the index.
+pub fn build_position_auction_index(
+    auctions: &[DutchAuction],
+    positions: &[Position],
+) -> BTreeMap<position::Id, AuctionId> {
+    let controlled: BTreeMap<position::Id, AuctionId> = auctions
+        .iter()
+        .filter_map(|auction| {
+            auction
+                .state
+                .current_position
+                .map(|position_id| (position_id, auction.description.id()))
+        })
+        .collect();
+
+    positions
+        .iter()
+        .map(Position::id)
+        .filter_map(|position_id| {
+            controlled
+                .get(&position_id)
+                .map(|auction_id| (position_id, *auction_id))
+        })
+        .collect()
+}
+
+/// Group auctions by their directed `(input, output)` asset pair, so that all
+/// the auctions selling the same asset for the same target can be rendered together.
+pub fn group_auctions_by_pair(
+    auctions: &[(DutchAuctionDescription, DutchAuctionState)],
+) -> BTreeMap<(asset::Id, asset::Id), Vec<AuctionId>> {
+    let mut groups: BTreeMap<(asset::Id, asset::Id), Vec<AuctionId>> = BTreeMap::new();
+    for (description, _) in auctions {
+        groups
+            .entry((description.input.asset_id, description.output_id))
+            .or_default()
+            .push(description.id());
+    }
+    groups
+}
+
+/// Collect the directed `(input, output)` asset pairs that have at least one active auction.
+pub fn active_auction_pairs(
+    auctions: &[(DutchAuctionDescription, DutchAuctionState)],
+) -> BTreeSet<(asset::Id, asset::Id)> {
+    auctions
+        .iter()
+        .filter(|(_, state)| state.sequence == 0)
+        .map(|(description, _)| (description.input.asset_id, description.output_id))
+        .collect()
+}
+
+/// Count the active auctions that trade each asset, either as their input or as their output.
+pub fn auction_count_by_asset(
+    auctions: &[(DutchAuctionDescription, DutchAuctionState)],
+) -> BTreeMap<asset::Id, usize> {
+    let mut counts: BTreeMap<asset::Id, usize> = BTreeMap::new();
+    for (description, _) in auctions.iter().filter(|(_, state)| state.sequence == 0) {
+        let assets = BTreeSet::from([description.input.asset_id, description.output_id]);
+        for asset_id in assets {
+            *counts.entry(asset_id).or_default() += 1;
+        }
+    }
+    counts
+}
+
+/// Sum the input remaining in active auctions, per input asset.
+///
+/// While an auction has a liquidity position deployed, its remaining input is held by that
+/// position rather than by the auction reserves, so each auction is supplied along with its
+/// current position, if any, e.g. as returned by the `AuctionStateById` query. The input
+/// reserves of the position are counted along with those of the auction.
+pub fn total_input_committed(
+    auctions: &[(DutchAuctionDescription, DutchAuctionState, Option<Position>)],
+) -> BTreeMap<asset::Id, Amount> {
+    let mut totals: BTreeMap<asset::Id, Amount> = BTreeMap::new();
+    for (description, state, position) in
+        auctions.iter().filter(|(_, state, _)| state.sequence == 0)
+    {
+        let input_id = description.input.asset_id;
+        let in_position = position
+            .as_ref()
+            .and_then(|position| position.reserves_for(input_id))
+            .unwrap_or_default();
+
+        let total = totals.entry(input_id).or_default();
+        *total = total
+            .saturating_add(&state.input_reserves)
+            .saturating_add(&in_position);
+    }
+    totals
+}
+
+/// The outcome of reconciling a wallet's auction NFTs against on-chain auction states,
+/// see [`reconcile_auction_nfts`].
+#[derive(Clone, Debug, Default)]
+pub struct AuctionReconciliation {
+    /// NFTs whose auction has advanced to a later sequence number on-chain.
+    pub stale: Vec<AuctionNft>,
+    /// NFTs that match the sequence number of their auction on-chain.
+    pub current: Vec<AuctionNft>,
+    /// NFTs for which no auction state was supplied.
+    pub orphaned: Vec<AuctionNft>,
+}
+
+/// Sort a wallet's auction NFTs by whether they are up to date with the supplied auction states.
+///
+/// An NFT with a later sequence number than its auction state is considered current, since
+/// the state can only lag behind the NFTs minted by the chain.
+pub fn reconcile_auction_nfts(
+    nfts: &[AuctionNft],
+    states: &BTreeMap<AuctionId, DutchAuctionState>,
+) -> AuctionReconciliation {
+    let mut reconciliation = AuctionReconciliation::default();
+    for nft in nfts {
+        let bucket = match states.get(&nft.id) {
+            None => &mut reconciliation.orphaned,
+            Some(state) if state.sequence > nft.seq => &mut reconciliation.stale,
+            Some(_) => &mut reconciliation.current,
+        };
+        bucket.push(nft.clone());
+    }
+    reconciliation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use penumbra_sdk_asset::Value;
+    use penumbra_sdk_dex::{lp::Reserves, DirectedTradingPair};
+
+    fn position(nonce: u8, input: asset::Id, output: asset::Id) -> Position {
+        Position::new_with_nonce(
+            [nonce; 32],
+            DirectedTradingPair::new(input, output),
+            0u32,
+            2u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: 100u64.into(),
+                r2: Amount::zero(),
+            },
+        )
+    }
+
+    fn description(
+        nonce: u8,
+        input_id: asset::Id,
+        output_id: asset::Id,
+    ) -> DutchAuctionDescription {
+        DutchAuctionDescription {
+            input: Value {
+                amount: 100u64.into(),
+                asset_id: input_id,
+            },
+            output_id,
+            max_output: 200u64.into(),
+            min_output: 100u64.into(),
+            start_height: 100,
+            end_height: 200,
+            step_count: 10,
+            nonce: [nonce; 32],
+        }
+    }
+
+    fn state(sequence: u64, current_position: Option<position::Id>) -> DutchAuctionState {
+        DutchAuctionState {
+            sequence,
+            current_position,
+            next_trigger: None,
+            input_reserves: Amount::zero(),
+            output_reserves: Amount::zero(),
+        }
+    }
+
+    fn auction(nonce: u8, current_position: Option<position::Id>) -> DutchAuction {
+        let cache = asset::Cache::with_known_assets();
+        let input_id = cache.get_unit("upenumbra").unwrap().id();
+        let output_id = cache.get_unit("gm").unwrap().id();
+
+        DutchAuction {
+            description: description(nonce, input_id, output_id),
+            state: state(0, current_position),
+        }
+    }
+
+    #[test]
+    fn index_only_contains_auction_controlled_positions() {
+        let cache = asset::Cache::with_known_assets();
+        let input_id = cache.get_unit("upenumbra").unwrap().id();
+        let output_id = cache.get_unit("gm").unwrap().id();
+
+        let auction_lp = position(1, input_id, output_id);
+        let user_lp = position(2, input_id, output_id);
+
+        let controlling = auction(1, Some(auction_lp.id()));
+        let idle = auction(2, None);
+
+        let index = build_position_auction_index(
+            &[controlling.clone(), idle],
+            &[auction_lp.clone(), user_lp.clone()],
+        );
+
+        assert_eq!(index.len(), 1);
+        assert_eq!(
+            index.get(&auction_lp.id()),
+            Some(&controlling.description.id())
+        );
+        assert!(!index.contains_key(&user_lp.id()));
+    }
+
+    #[test]
+    fn auctions_are_grouped_by_directed_pair() {
+        let cache = asset::Cache::with_known_assets();
+        let penumbra = cache.get_unit("upenumbra").unwrap().id();
+        let gm = cache.get_unit("gm").unwrap().id();
+        let gn = cache.get_unit("gn").unwrap().id();
+
+        let first = description(1, penumbra, gm);
+        let second = description(2, penumbra, gm);
+        let third = description(3, penumbra, gn);
+
+        let auctions = vec![
+            (first.clone(), state(0, None)),
+            (second.clone(), state(1, None)),
+            (third.clone(), state(0, None)),
+        ];
+        let groups = group_auctions_by_pair(&auctions);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(
+            groups.get(&(penumbra, gm)),
+            Some(&vec![first.id(), second.id()])
+        );
+        assert_eq!(groups.get(&(penumbra, gn)), Some(&vec![third.id()]));
+    }
+
+    #[test]
+    fn active_pairs_exclude_closed_auctions() {
+        let cache = asset::Cache::with_known_assets();
+        let penumbra = cache.get_unit("upenumbra").unwrap().id();
+        let gm = cache.get_unit("gm").unwrap().id();
+        let gn = cache.get_unit("gn").unwrap().id();
+
+        let auctions = vec![
+            (description(1, penumbra, gm), state(0, None)),
+            (description(2, penumbra, gm), state(1, None)),
+            (description(3, gm, penumbra), state(0, None)),
+            (description(4, penumbra, gn), state(2, None)),
+        ];
+
+        let pairs = active_auction_pairs(&auctions);
+        assert_eq!(pairs, BTreeSet::from([(penumbra, gm), (gm, penumbra)]));
+    }
+
+    #[test]
+    fn active_auctions_are_counted_per_asset() {
+        let cache = asset::Cache::with_known_assets();
+        let penumbra = cache.get_unit("upenumbra").unwrap().id();
+        let gm = cache.get_unit("gm").unwrap().id();
+        let gn = cache.get_unit("gn").unwrap().id();
+
+        let auctions = vec![
+            (description(1, penumbra, gm), state(0, None)),
+            (description(2, gm, penumbra), state(0, None)),
+            (description(3, penumbra, gn), state(0, None)),
+            (description(4, gn, gm), state(1, None)),
+        ];
+
+        let counts = auction_count_by_asset(&auctions);
+        assert_eq!(counts, BTreeMap::from([(penumbra, 3), (gm, 2), (gn, 1)]));
+    }
+
+    #[test]
+    fn input_is_summed_across_active_auctions() {
+        let cache = asset::Cache::with_known_assets();
+        let penumbra = cache.get_unit("upenumbra").unwrap().id();
+        let gm = cache.get_unit("gm").unwrap().id();
+
+        // Awaiting its first position: the input sits in the reserves.
+        let mut pending = state(0, None);
+        pending.input_reserves = 100u64.into();
+        // Executing: the remaining input is held by the position, after part of it was sold.
+        let deployed = Position::new_with_nonce(
+            [1u8; 32],
+            DirectedTradingPair::new(penumbra, gm),
+            0u32,
+            2u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: 40u64.into(),
+                r2: 120u64.into(),
+            },
+        );
+        let executing = state(0, Some(deployed.id()));
+        // Closed auctions are no longer active.
+        let mut closed = state(1, None);
+        closed.input_reserves = 100u64.into();
+
+        let auctions = vec![
+            (description(1, penumbra, gm), pending, None),
+            (description(2, penumbra, gm), executing, Some(deployed)),
+            (description(3, penumbra, gm), closed, None),
+        ];
+
+        let totals = total_input_committed(&auctions);
+        assert_eq!(totals.len(), 1);
+        assert_eq!(totals.get(&penumbra), Some(&Amount::from(140u64)));
+    }
+
+    #[test]
+    fn auction_nfts_are_reconciled() {
+        let cache = asset::Cache::with_known_assets();
+        let penumbra = cache.get_unit("upenumbra").unwrap().id();
+        let gm = cache.get_unit("gm").unwrap().id();
+        let [first, second, unknown] = [1, 2, 3].map(|nonce| description(nonce, penumbra, gm).id());
+
+        let states = BTreeMap::from([(first, state(1, None)), (second, state(1, None))]);
+        let nfts = [
+            AuctionNft::new(first, 0),
+            AuctionNft::new(second, 1),
+            AuctionNft::new(unknown, 0),
+        ];
+
+        let reconciliation = reconcile_auction_nfts(&nfts, &states);
+        let ids =
+            |nfts: &[AuctionNft]| nfts.iter().map(|nft| (nft.id, nft.seq)).collect::<Vec<_>>();
+        assert_eq!(ids(&reconciliation.stale), vec![(first, 0)]);
+        assert_eq!(ids(&reconciliation.current), vec![(second, 1)]);
+        assert_eq!(ids(&reconciliation.orphaned), vec![(unknown, 0)]);
+    }
+}
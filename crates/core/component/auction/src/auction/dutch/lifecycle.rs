@@ -0,0 +1,143 @@
+//! Typed lifecycle events for Dutch auctions, derived from state snapshots.
+
+use crate::auction::dutch::DutchAuctionState;
+
+/// A transition in the lifecycle of a Dutch auction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuctionEvent {
+    /// The auction was scheduled and is awaiting execution.
+    Scheduled,
+    /// The auction deployed its first liquidity position and started trading.
+    Opened,
+    /// The auction was closed, either by its owner, by reaching its end height,
+    /// or by exhausting its input reserves.
+    Closed,
+    /// The auction reserves were withdrawn.
+    Withdrawn,
+}
+
+impl AuctionEvent {
+    /// Compute the events that occurred between two state snapshots of the same auction.
+    ///
+    /// If `previous` is `None`, `next` is treated as the first observation of the
+    /// auction, and a [`AuctionEvent::Scheduled`] event is emitted first.
+    ///
+    /// Snapshots can skip intermediate states (e.g. an auction observed as opened,
+    /// then as withdrawn), in which case the elided [`AuctionEvent::Closed`] and
+    /// [`AuctionEvent::Withdrawn`] transitions are emitted in order. An elided
+    /// [`AuctionEvent::Opened`] is not: an auction can be ended before it deploys its
+    /// first position, so a jump from a scheduled auction to a closed or withdrawn
+    /// one doesn't tell whether it ever opened.
+    pub fn between(
+        previous: Option<&DutchAuctionState>,
+        next: &DutchAuctionState,
+    ) -> Vec<AuctionEvent> {
+        let mut events = Vec::new();
+
+        let (prev_sequence, prev_has_traded) = match previous {
+            Some(state) => (state.sequence, state.current_position.is_some()),
+            None => {
+                events.push(AuctionEvent::Scheduled);
+                (0, false)
+            }
+        };
+
+        // An auction opens when it deploys its first liquidity position.
+        if prev_sequence == 0
+            && !prev_has_traded
+            && next.sequence == 0
+            && next.current_position.is_some()
+        {
+            events.push(AuctionEvent::Opened);
+        }
+
+        if prev_sequence == 0 && next.sequence >= 1 {
+            events.push(AuctionEvent::Closed);
+        }
+
+        if next.sequence >= 2 && next.sequence > prev_sequence {
+            events.push(AuctionEvent::Withdrawn);
+        }
+
+        events
+    }
+}
+
+/// Compute the lifecycle events that occurred over a sequence of consecutive
+/// state snapshots of the same auction.
+///
+/// Only transitions *between* snapshots are reported: the first snapshot is
+/// used as a starting point, and does not produce any event on its own.
+pub fn lifecycle_events(snapshots: &[DutchAuctionState]) -> Vec<AuctionEvent> {
+    snapshots
+        .windows(2)
+        .flat_map(|pair| AuctionEvent::between(Some(&pair[0]), &pair[1]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use penumbra_sdk_dex::lp::position;
+    use penumbra_sdk_num::Amount;
+    use std::num::NonZeroU64;
+
+    fn state(sequence: u64, current_position: Option<position::Id>) -> DutchAuctionState {
+        DutchAuctionState {
+            sequence,
+            current_position,
+            next_trigger: current_position.and(NonZeroU64::new(120)),
+            input_reserves: Amount::zero(),
+            output_reserves: Amount::zero(),
+        }
+    }
+
+    #[test]
+    fn closed_then_withdrawn() {
+        let snapshots = [
+            state(0, Some(position::Id([1u8; 32]))),
+            state(1, None),
+            state(2, None),
+        ];
+
+        assert_eq!(
+            lifecycle_events(&snapshots),
+            vec![AuctionEvent::Closed, AuctionEvent::Withdrawn]
+        );
+    }
+
+    #[test]
+    fn skipped_transitions() {
+        let opened = state(0, Some(position::Id([1u8; 32])));
+        assert_eq!(
+            AuctionEvent::between(Some(&opened), &state(2, None)),
+            vec![AuctionEvent::Closed, AuctionEvent::Withdrawn]
+        );
+
+        // Whether the auction opened before being closed can't be told.
+        let scheduled = state(0, None);
+        assert_eq!(
+            AuctionEvent::between(Some(&scheduled), &state(1, None)),
+            vec![AuctionEvent::Closed]
+        );
+        assert_eq!(
+            AuctionEvent::between(Some(&scheduled), &state(2, None)),
+            vec![AuctionEvent::Closed, AuctionEvent::Withdrawn]
+        );
+    }
+
+    #[test]
+    fn first_observation_is_scheduled() {
+        assert_eq!(
+            AuctionEvent::between(None, &state(0, None)),
+            vec![AuctionEvent::Scheduled]
+        );
+        assert_eq!(
+            AuctionEvent::between(
+                Some(&state(0, None)),
+                &state(0, Some(position::Id([1u8; 32])))
+            ),
+            vec![AuctionEvent::Opened]
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Client-side bookkeeping of the nonces used to schedule Dutch auctions.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::auction::{dutch::DutchAuctionDescription, AuctionId};
+
+/// A record of the auctions a wallet has scheduled, used to avoid reusing a nonce.
+///
+/// The id of an auction is derived from its nonce together with its input, bounds and
+/// schedule (see [`DutchAuctionDescription::id`]), so reusing a nonce for the same
+/// parameters would produce two auctions with the same id. The registry is keyed by
+/// those ids, and can be persisted by wallets through its serde representation.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonceRegistry {
+    used: BTreeSet<AuctionId>,
+}
+
+impl NonceRegistry {
+    /// Check that the nonce of the description wasn't already used for the same auction.
+    pub fn check(&self, description: &DutchAuctionDescription) -> anyhow::Result<()> {
+        let id = description.id();
+        anyhow::ensure!(
+            !self.used.contains(&id),
+            "nonce was already used to schedule auction {id}"
+        );
+        Ok(())
+    }
+
+    /// Record the nonce of the description, failing if it was already used for the same auction.
+    pub fn register(&mut self, description: &DutchAuctionDescription) -> anyhow::Result<AuctionId> {
+        self.check(description)?;
+        let id = description.id();
+        self.used.insert(id);
+        Ok(id)
+    }
+
+    /// Returns whether the auction with the given id was recorded.
+    pub fn contains(&self, id: &AuctionId) -> bool {
+        self.used.contains(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auction::dutch::sample_description;
+    use penumbra_sdk_asset::asset;
+
+    #[test]
+    fn nonce_reuse_is_rejected() {
+        let cache = asset::Cache::with_known_assets();
+        let description = sample_description();
+
+        let mut registry = NonceRegistry::default();
+        let id = registry.register(&description).unwrap();
+        assert!(registry.contains(&id));
+        assert!(registry.register(&description).is_err());
+
+        // The same nonce can't be reused by changing parameters the id doesn't commit to.
+        let other_output = DutchAuctionDescription {
+            output_id: cache.get_unit("gn").unwrap().id(),
+            ..description
+        };
+        assert!(registry.check(&other_output).is_err());
+
+        let fresh_nonce = DutchAuctionDescription {
+            nonce: [1u8; 32],
+            ..description
+        };
+        assert!(registry.register(&fresh_nonce).is_ok());
+
+        // The registry survives being persisted and reloaded.
+        let persisted = serde_json::to_string(&registry).unwrap();
+        let reloaded: NonceRegistry = serde_json::from_str(&persisted).unwrap();
+        assert_eq!(reloaded, registry);
+        assert!(reloaded.check(&fresh_nonce).is_err());
+    }
+}
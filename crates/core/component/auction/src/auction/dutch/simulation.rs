@@ -0,0 +1,82 @@
+//! Dry-run previews of Dutch auction schedules.
+
+use penumbra_sdk_num::Amount;
+
+use crate::auction::{dutch::DutchAuctionDescription, AuctionId};
+
+/// A preview of how a Dutch auction would execute if scheduled, computed
+/// without touching the chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuctionSimulation {
+    /// The identifier the auction would be assigned.
+    pub auction_id: AuctionId,
+    /// The number of blocks until the auction starts, or `None` if the auction
+    /// does not start in the future and would be rejected by the chain.
+    pub starts_in: Option<u64>,
+    /// The total duration of the auction, in blocks.
+    pub duration: u64,
+    /// The heights at which the auction is stepped by the chain. The last
+    /// trigger height is the end height, at which the auction is closed.
+    pub trigger_heights: Vec<u64>,
+    /// The price schedule of the auction, as pairs of the height at which
+    /// a liquidity position is deployed and the output offered for the
+    /// entire input at that step.
+    pub price_schedule: Vec<(u64, Amount)>,
+}
+
+/// Simulate the execution of an auction with the supplied description,
+/// if it were scheduled at `current_height`.
+pub fn simulate_auction(
+    description: &DutchAuctionDescription,
+    current_height: u64,
+) -> AuctionSimulation {
+    let trigger_heights = description.trigger_heights();
+
+    // The final trigger closes the auction rather than deploying a position.
+    let price_schedule = trigger_heights
+        .iter()
+        .zip(1u64..description.step_count)
+        .map(|(height, step_index)| (*height, description.output_at_step(step_index)))
+        .collect();
+
+    AuctionSimulation {
+        auction_id: description.id(),
+        starts_in: description
+            .start_height
+            .checked_sub(current_height)
+            .filter(|blocks| *blocks > 0),
+        duration: description
+            .end_height
+            .saturating_sub(description.start_height),
+        trigger_heights,
+        price_schedule,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auction::dutch::sample_description;
+
+    #[test]
+    fn simulate_sample_auction() {
+        let description = sample_description();
+
+        let simulation = simulate_auction(&description, 50);
+
+        assert_eq!(simulation.auction_id, description.id());
+        assert_eq!(simulation.starts_in, Some(50));
+        assert_eq!(simulation.duration, 100);
+        assert_eq!(
+            simulation.trigger_heights,
+            vec![110, 120, 130, 140, 150, 160, 170, 180, 190, 200]
+        );
+        assert_eq!(simulation.price_schedule.len(), 9);
+        // (8 * 1000 + 1 * 250) / 9, rounded down.
+        assert_eq!(simulation.price_schedule[0], (110, 916u64.into()));
+        assert_eq!(simulation.price_schedule[8], (190, 250u64.into()));
+
+        // An auction can't be scheduled once its start height has passed.
+        assert_eq!(simulate_auction(&description, 100).starts_in, None);
+    }
+}
@@ -10,13 +10,28 @@ use serde::{Deserialize, Serialize};
 #[serde(try_from = "pb::AuctionId", into = "pb::AuctionId")]
 pub struct AuctionId(pub [u8; 32]);
 
+impl AuctionId {
+    /// A stable RGB color for the auction, so that UIs can consistently color-code it.
+    ///
+    /// Since the auction id is a hash, its leading bytes are already uniformly distributed.
+    pub fn display_color(&self) -> [u8; 3] {
+        [self.0[0], self.0[1], self.0[2]]
+    }
+}
+
 /* Basic impls */
 impl std::str::FromStr for AuctionId {
     type Err = anyhow::Error;
 
     // IMPORTANT: changing this is state-breaking.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let inner = bech32str::decode(s, bech32str::auction_id::BECH32_PREFIX, bech32str::Bech32m)?;
+        let inner = bech32str::decode(s, bech32str::auction_id::BECH32_PREFIX, bech32str::Bech32m)
+            .with_context(|| {
+                format!(
+                    "auction id MUST be a bech32m string with the `{}` prefix",
+                    bech32str::auction_id::BECH32_PREFIX
+                )
+            })?;
         pb::AuctionId { inner }.try_into()
     }
 }
@@ -67,3 +82,52 @@ impl TryFrom<pb::AuctionId> for AuctionId {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn string_roundtrip() {
+        let id = AuctionId([42u8; 32]);
+        let encoded = id.to_string();
+        assert!(encoded.starts_with(bech32str::auction_id::BECH32_PREFIX));
+        assert_eq!(AuctionId::from_str(&encoded).unwrap(), id);
+
+        // A different prefix, a corrupted checksum and a wrong length are all rejected.
+        let position_id = bech32str::encode(
+            &[42u8; 32],
+            bech32str::lp_id::BECH32_PREFIX,
+            bech32str::Bech32m,
+        );
+        assert!(AuctionId::from_str(&position_id).is_err());
+        let mut corrupted = encoded.clone();
+        corrupted.pop();
+        corrupted.push(if encoded.ends_with('q') { 'p' } else { 'q' });
+        assert!(AuctionId::from_str(&corrupted).is_err());
+        let short = bech32str::encode(
+            &[42u8; 16],
+            bech32str::auction_id::BECH32_PREFIX,
+            bech32str::Bech32m,
+        );
+        assert!(AuctionId::from_str(&short).is_err());
+        assert!(AuctionId::from_str("not an auction id").is_err());
+    }
+
+    #[test]
+    fn display_color_is_stable() {
+        let id = AuctionId([7u8; 32]);
+        assert_eq!(id.display_color(), AuctionId([7u8; 32]).display_color());
+
+        let colors: std::collections::BTreeSet<[u8; 3]> = (0u8..16)
+            .map(|i| {
+                let hash = blake2b_simd::blake2b(&[i]);
+                let mut bytes = [0u8; 32];
+                bytes.copy_from_slice(&hash.as_bytes()[..32]);
+                AuctionId(bytes).display_color()
+            })
+            .collect();
+        assert_eq!(colors.len(), 16);
+    }
+}
@@ -1,6 +1,9 @@
 use crate::auction::id::AuctionId;
 use anyhow::{anyhow, Result};
-use penumbra_sdk_asset::asset::{self, Metadata};
+use penumbra_sdk_asset::{
+    asset::{self, Metadata},
+    Value,
+};
 use penumbra_sdk_proto::{core::component::auction::v1 as pb, DomainType};
 use regex::Regex;
 
@@ -19,14 +22,63 @@ pub struct AuctionNft {
 impl AuctionNft {
     pub fn new(id: AuctionId, seq: u64) -> AuctionNft {
         let metadata = asset::REGISTRY
-            .parse_denom(&format!("auctionnft_{seq}_{id}"))
+            .parse_denom(&nft_denom(id, seq))
             .expect("auction nft denom is valid");
         AuctionNft { id, seq, metadata }
     }
 
+    /// The denom of the NFT, of the form `auctionnft_<seq>_<auction id>`.
+    ///
+    /// This is the base denom of [`AuctionNft::metadata`], which wallets can use to display
+    /// the NFT in balance views.
+    pub fn denom(&self) -> String {
+        nft_denom(self.id, self.seq)
+    }
+
+    /// The NFT held by the owner of the auction `id` once it reaches the supplied stage.
+    pub fn for_stage(id: AuctionId, stage: AuctionSequence) -> AuctionNft {
+        AuctionNft::new(id, stage.seq())
+    }
+
     pub fn asset_id(&self) -> asset::Id {
         self.metadata.id()
     }
+
+    /// The NFT as a value, i.e. one unit of the NFT asset, for balancing
+    /// the actions that mint or burn it.
+    pub fn value(&self) -> Value {
+        Value {
+            amount: 1u128.into(),
+            asset_id: self.asset_id(),
+        }
+    }
+}
+
+fn nft_denom(id: AuctionId, seq: u64) -> String {
+    format!("auctionnft_{seq}_{id}")
+}
+
+/// A stage in the lifecycle of an auction, as tracked by the sequence number
+/// of the auction NFT.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuctionSequence {
+    /// The auction is scheduled or executing.
+    Opened,
+    /// The auction has ended, but its reserves have not been withdrawn.
+    Closed,
+    /// The auction reserves have been withdrawn.
+    Withdrawn,
+}
+
+impl AuctionSequence {
+    /// The auction NFT sequence number corresponding to this stage.
+    pub fn seq(&self) -> u64 {
+        match self {
+            AuctionSequence::Opened => 0,
+            AuctionSequence::Closed => 1,
+            AuctionSequence::Withdrawn => 2,
+        }
+    }
 }
 
 /* Protobuf impls ;*/
@@ -91,3 +143,58 @@ impl TryFrom<Metadata> for AuctionNft {
         Ok(AuctionNft::new(auction_id, seq_num))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nft_value() {
+        let nft = AuctionNft::new(AuctionId([1u8; 32]), 0);
+        let denom = asset::REGISTRY
+            .parse_denom(&format!("auctionnft_0_{}", nft.id))
+            .unwrap();
+
+        assert_eq!(nft.asset_id(), denom.id());
+        assert_eq!(
+            nft.value(),
+            Value {
+                amount: 1u64.into(),
+                asset_id: denom.id(),
+            }
+        );
+    }
+
+    #[test]
+    fn nft_for_each_stage() {
+        let id = AuctionId([1u8; 32]);
+
+        for (stage, seq) in [
+            (AuctionSequence::Opened, 0),
+            (AuctionSequence::Closed, 1),
+            (AuctionSequence::Withdrawn, 2),
+        ] {
+            let nft = AuctionNft::for_stage(id, stage);
+            assert_eq!(nft.id, id);
+            assert_eq!(nft.seq, seq);
+            assert_eq!(nft.asset_id(), AuctionNft::new(id, seq).asset_id());
+        }
+    }
+
+    #[test]
+    fn denom_roundtrip() {
+        let id = AuctionId([1u8; 32]);
+        let (opened, later) = (AuctionNft::new(id, 0), AuctionNft::new(id, 3));
+
+        assert_eq!(opened.denom(), format!("auctionnft_0_{id}"));
+        assert_ne!(opened.denom(), later.denom());
+        assert_eq!(later.metadata.base_denom().denom, later.denom());
+
+        for nft in [opened, later] {
+            let metadata = asset::REGISTRY.parse_denom(&nft.denom()).unwrap();
+            let parsed = AuctionNft::try_from(metadata).unwrap();
+            assert_eq!(parsed.id, nft.id);
+            assert_eq!(parsed.seq, nft.seq);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::Context;
+use penumbra_sdk_dex::lp::position::Position;
+use penumbra_sdk_proto::{core::component::auction::v1 as pb, DomainType};
+use prost::Name;
+
+use crate::auction::dutch::DutchAuction;
+
+/// The state of an auction, as returned by an `AuctionStateById` query.
+#[derive(Debug, Clone)]
+pub struct AuctionStateView {
+    /// The state of the auction, if it is a known Dutch auction.
+    pub auction: Option<DutchAuction>,
+    /// The DEX positions controlled by the auction.
+    pub positions: Vec<Position>,
+}
+
+impl AuctionStateView {
+    /// Returns whether the auction currently controls a DEX position.
+    pub fn has_active_position(&self) -> bool {
+        !self.positions.is_empty()
+    }
+
+    /// The number of DEX positions controlled by the auction.
+    pub fn position_count(&self) -> usize {
+        self.positions.len()
+    }
+}
+
+impl TryFrom<pb::AuctionStateByIdResponse> for AuctionStateView {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::AuctionStateByIdResponse) -> Result<Self, Self::Error> {
+        let auction = match msg.auction {
+            Some(any) if any.type_url == pb::DutchAuction::type_url() => Some(
+                DutchAuction::decode(any.value.as_ref())
+                    .context("error deserializing dutch auction state")?,
+            ),
+            _ => None,
+        };
+        let positions = msg
+            .positions
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(AuctionStateView { auction, positions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auction::dutch::{sample_description, DutchAuctionState};
+    use penumbra_sdk_dex::{lp::Reserves, DirectedTradingPair};
+    use penumbra_sdk_num::Amount;
+
+    #[test]
+    fn state_view_counts_positions() {
+        let description = sample_description();
+        let input_id = description.input.asset_id;
+        let output_id = description.output_id;
+        let auction = DutchAuction {
+            description,
+            state: DutchAuctionState {
+                sequence: 0,
+                current_position: None,
+                next_trigger: None,
+                input_reserves: 100u64.into(),
+                output_reserves: Amount::zero(),
+            },
+        };
+        let position = |nonce: u8| {
+            Position::new_with_nonce(
+                [nonce; 32],
+                DirectedTradingPair::new(input_id, output_id),
+                0u32,
+                2u64.into(),
+                1u64.into(),
+                Reserves {
+                    r1: 100u64.into(),
+                    r2: Amount::zero(),
+                },
+            )
+        };
+        let response = |positions: Vec<Position>| pb::AuctionStateByIdResponse {
+            auction: Some(pbjson_types::Any {
+                type_url: pb::DutchAuction::type_url(),
+                value: auction.encode_to_vec().into(),
+            }),
+            positions: positions.into_iter().map(Into::into).collect(),
+        };
+
+        let idle = AuctionStateView::try_from(response(vec![])).unwrap();
+        assert_eq!(idle.auction, Some(auction.clone()));
+        assert!(!idle.has_active_position());
+        assert_eq!(idle.position_count(), 0);
+
+        let active = AuctionStateView::try_from(response(vec![position(1), position(2)])).unwrap();
+        assert!(active.has_active_position());
+        assert_eq!(active.position_count(), 2);
+
+        // Auctions of other types aren't decoded.
+        let mut unknown = response(vec![]);
+        unknown.auction.as_mut().unwrap().type_url = "/unknown.Auction".to_string();
+        assert!(AuctionStateView::try_from(unknown)
+            .unwrap()
+            .auction
+            .is_none());
+    }
+}
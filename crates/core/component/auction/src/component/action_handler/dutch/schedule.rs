@@ -1,6 +1,7 @@
 use crate::auction::dutch::actions::schedule::MAX_AUCTION_AMOUNT_RESERVES;
 use crate::auction::dutch::DutchAuctionDescription;
-use crate::component::AuctionStoreRead;
+use crate::component::{AuctionStoreRead, StateReadExt as _};
+use crate::params::MAX_AUCTION_STEP_COUNT;
 use anyhow::{ensure, Result};
 use async_trait::async_trait;
 use cnidarium::StateWrite;
@@ -86,10 +87,11 @@ impl ActionHandler for ActionDutchAuctionSchedule {
             "step count MUST be at least two (got: {step_count})"
         );
 
-        // Check that the step count is less than 255.
+        // Check that the step count is at most 255. The chain parameters can
+        // lower this bound further, which is checked statefully.
         ensure!(
-            step_count <= 255,
-            "the dutch auction step count MUST be less than 255 (got: {step_count})",
+            step_count <= MAX_AUCTION_STEP_COUNT,
+            "the dutch auction step count MUST be at most {MAX_AUCTION_STEP_COUNT} (got: {step_count})",
         );
 
         // Check that height delta is a multiple of `step_count`.
@@ -119,6 +121,12 @@ impl ActionHandler for ActionDutchAuctionSchedule {
             current_height
         );
 
+        // Check that the step count is within the bounds set by the chain parameters.
+        let auction_params = state.get_auction_params().await?;
+        schedule
+            .description
+            .validate_against_params(&auction_params)?;
+
         // Check that the `auction_id` is unused.
         let id = schedule.description.id();
         ensure!(
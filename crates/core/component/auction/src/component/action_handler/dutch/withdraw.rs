@@ -1,4 +1,4 @@
-use crate::auction::dutch::ActionDutchAuctionWithdraw;
+use crate::auction::dutch::{validate_withdraw_seq, ActionDutchAuctionWithdraw};
 use crate::component::AuctionStoreRead;
 use crate::component::DutchAuctionManager;
 use anyhow::{bail, ensure, Context, Result};
@@ -40,12 +40,7 @@ impl ActionHandler for ActionDutchAuctionWithdraw {
         };
 
         // Check that sequence number is incremented by one.
-        ensure!(
-            self.seq == auction_state.state.sequence.saturating_add(1),
-            "the action sequence number MUST be incremented by one (previous: {}, action: {})",
-            self.seq,
-            auction_state.state.sequence
-        );
+        validate_withdraw_seq(self, &auction_state.state)?;
 
         // Execute the withdrawal, zero-ing out the auction state
         // and increasing its sequence number.
@@ -1,11 +1,15 @@
 #![allow(unused)] // TODO: remove this when filling in the RPCs
 
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
 use penumbra_sdk_dex::{component::PositionRead, lp::position};
 use penumbra_sdk_proto::{
     core::component::auction::v1 as pb,
     core::component::auction::v1::{
-        query_service_server::QueryService, AuctionStateByIdRequest, AuctionStateByIdResponse,
-        AuctionStateByIdsRequest, AuctionStateByIdsResponse, DutchAuctionState,
+        query_service_client::QueryServiceClient, query_service_server::QueryService,
+        AuctionStateByIdRequest, AuctionStateByIdResponse, AuctionStateByIdsRequest,
+        AuctionStateByIdsResponse, DutchAuctionState,
     },
     DomainType,
 };
@@ -18,11 +22,14 @@ use std::pin::Pin;
 use tonic::Status;
 use tracing::instrument;
 
-use crate::auction::dutch::DutchAuction;
+use crate::auction::{dutch::DutchAuction, AuctionId};
 
 use super::{action_handler::dutch, AuctionStoreRead};
 use cnidarium::Storage;
 
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+
 pub struct Server {
     storage: Storage,
 }
@@ -94,3 +101,176 @@ impl QueryService for Server {
         todo!()
     }
 }
+
+/// Convenience methods on top of the auction query service client.
+#[tonic::async_trait]
+pub trait QueryServiceClientExt {
+    /// Fetch the states of the supplied auctions, omitting the auctions that are unknown
+    /// to the node.
+    ///
+    /// The ids are requested in chunks of at most `chunk_size` ids, each through a call to
+    /// the streaming `AuctionStateByIds` endpoint, so the limits configured on the client
+    /// (e.g. `max_decoding_message_size`) apply to every response. Transport errors are
+    /// propagated.
+    async fn auction_states_by_ids(
+        &mut self,
+        ids: &[AuctionId],
+        chunk_size: usize,
+    ) -> anyhow::Result<BTreeMap<AuctionId, crate::auction::dutch::DutchAuctionState>>;
+}
+
+#[tonic::async_trait]
+impl QueryServiceClientExt for QueryServiceClient<tonic::transport::Channel> {
+    async fn auction_states_by_ids(
+        &mut self,
+        ids: &[AuctionId],
+        chunk_size: usize,
+    ) -> anyhow::Result<BTreeMap<AuctionId, crate::auction::dutch::DutchAuctionState>> {
+        let mut states = BTreeMap::new();
+        for chunk in ids.chunks(chunk_size.max(1)) {
+            let request = AuctionStateByIdsRequest {
+                id: chunk.iter().copied().map(Into::into).collect(),
+            };
+            let mut stream = self.auction_state_by_ids(request).await?.into_inner();
+
+            while let Some(response) = stream.message().await? {
+                let id: AuctionId = response
+                    .id
+                    .ok_or_else(|| anyhow!("AuctionStateByIdsResponse is missing an id"))?
+                    .try_into()?;
+                let state = response
+                    .auction
+                    .ok_or_else(|| anyhow!("AuctionStateByIdsResponse is missing an auction"))?
+                    .try_into()?;
+                if chunk.contains(&id) {
+                    states.insert(id, state);
+                }
+            }
+        }
+        Ok(states)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use penumbra_sdk_asset::{asset, Value};
+    use penumbra_sdk_dex::{
+        lp::{position::Position, Reserves},
+        DirectedTradingPair,
+    };
+    use penumbra_sdk_num::Amount;
+    use penumbra_sdk_proto::core::component::auction::v1::query_service_server::QueryServiceServer;
+
+    use crate::auction::{
+        dutch::{DutchAuctionDescription, DutchAuctionState as DomainState},
+        AuctionStateView,
+    };
+    use mock::MockAuctionQueryService;
+
+    async fn serve(service: MockAuctionQueryService) -> anyhow::Result<std::net::SocketAddr> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(QueryServiceServer::new(service))
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener)),
+        );
+        Ok(addr)
+    }
+
+    fn auction(nonce: u8, sequence: u64) -> DutchAuction {
+        let cache = asset::Cache::with_known_assets();
+        DutchAuction {
+            description: DutchAuctionDescription {
+                input: Value {
+                    amount: 100u64.into(),
+                    asset_id: cache.get_unit("upenumbra").unwrap().id(),
+                },
+                output_id: cache.get_unit("gm").unwrap().id(),
+                max_output: 200u64.into(),
+                min_output: 100u64.into(),
+                start_height: 100,
+                end_height: 200,
+                step_count: 10,
+                nonce: [nonce; 32],
+            },
+            state: DomainState {
+                sequence,
+                current_position: None,
+                next_trigger: None,
+                input_reserves: Amount::from(100u64),
+                output_reserves: Amount::zero(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn mock_service_answers_unary_queries() -> anyhow::Result<()> {
+        let auction = auction(1, 0);
+        let position = Position::new_with_nonce(
+            [1u8; 32],
+            DirectedTradingPair::new(
+                auction.description.input.asset_id,
+                auction.description.output_id,
+            ),
+            0u32,
+            2u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: 100u64.into(),
+                r2: Amount::zero(),
+            },
+        );
+        let service = MockAuctionQueryService::new([(auction.clone(), vec![position.clone()])]);
+
+        let addr = serve(service).await?;
+        let mut client = QueryServiceClient::connect(format!("http://{addr}")).await?;
+        let query = |id: AuctionId| AuctionStateByIdRequest {
+            id: Some(id.into()),
+        };
+
+        let known: AuctionStateView = client
+            .auction_state_by_id(query(auction.description.id()))
+            .await?
+            .into_inner()
+            .try_into()?;
+        assert_eq!(known.auction, Some(auction));
+        assert_eq!(known.positions, vec![position]);
+
+        let unknown = client
+            .auction_state_by_id(query(AuctionId([0u8; 32])))
+            .await?
+            .into_inner();
+        assert!(unknown.auction.is_none());
+        assert!(unknown.positions.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn auction_states_by_ids_omits_unknown_auctions() -> anyhow::Result<()> {
+        let (first, second) = (auction(1, 0), auction(2, 1));
+        let unknown = AuctionId([3u8; 32]);
+        let service = MockAuctionQueryService::default()
+            .with_auction(first.clone(), Vec::new())
+            .with_auction(second.clone(), Vec::new());
+
+        let addr = serve(service).await?;
+        let mut client = QueryServiceClient::connect(format!("http://{addr}")).await?;
+        let states = client
+            .auction_states_by_ids(
+                &[first.description.id(), unknown, second.description.id()],
+                2,
+            )
+            .await?;
+
+        assert_eq!(
+            states,
+            BTreeMap::from([
+                (first.description.id(), first.state),
+                (second.description.id(), second.state)
+            ])
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use std::{collections::BTreeMap, pin::Pin};
+
+use futures::StreamExt;
+use penumbra_sdk_dex::lp::position::Position;
+use penumbra_sdk_proto::{
+    core::component::auction::v1::{
+        self as pb, query_service_server::QueryService, AuctionStateByIdRequest,
+        AuctionStateByIdResponse, AuctionStateByIdsRequest, AuctionStateByIdsResponse,
+    },
+    DomainType,
+};
+use prost::Name;
+use tonic::Status;
+
+use crate::auction::{dutch::DutchAuction, AuctionId};
+
+/// An in-memory auction [`QueryService`], for testing client code without a node.
+///
+/// The full auctions are held, rather than only their states, so that the unary
+/// `AuctionStateById` endpoint can return them like the node does. Unlike the node,
+/// unknown auctions are answered with an empty `auction` rather than an error.
+#[derive(Clone, Debug, Default)]
+pub struct MockAuctionQueryService {
+    auctions: BTreeMap<AuctionId, (DutchAuction, Vec<Position>)>,
+}
+
+impl MockAuctionQueryService {
+    /// Serve the supplied auctions, along with the DEX positions they control.
+    pub fn new(auctions: impl IntoIterator<Item = (DutchAuction, Vec<Position>)>) -> Self {
+        auctions
+            .into_iter()
+            .fold(Self::default(), |service, (auction, positions)| {
+                service.with_auction(auction, positions)
+            })
+    }
+
+    /// Add an auction, along with the DEX positions it controls.
+    pub fn with_auction(mut self, auction: DutchAuction, positions: Vec<Position>) -> Self {
+        self.auctions
+            .insert(auction.description.id(), (auction, positions));
+        self
+    }
+
+    fn state_by_ids_response(&self, id: AuctionId) -> Option<AuctionStateByIdsResponse> {
+        self.auctions
+            .get(&id)
+            .map(|(auction, positions)| AuctionStateByIdsResponse {
+                id: Some(id.into()),
+                auction: Some(auction.state.clone().into()),
+                positions: positions.iter().cloned().map(Into::into).collect(),
+            })
+    }
+}
+
+fn parse_id(id: Option<pb::AuctionId>) -> Result<AuctionId, Status> {
+    id.ok_or_else(|| Status::invalid_argument("missing auction id"))?
+        .try_into()
+        .map_err(|_| Status::invalid_argument("invalid auction id"))
+}
+
+#[tonic::async_trait]
+impl QueryService for MockAuctionQueryService {
+    async fn auction_state_by_id(
+        &self,
+        request: tonic::Request<AuctionStateByIdRequest>,
+    ) -> Result<tonic::Response<AuctionStateByIdResponse>, Status> {
+        let id = parse_id(request.into_inner().id)?;
+
+        let response = match self.auctions.get(&id) {
+            Some((auction, positions)) => AuctionStateByIdResponse {
+                auction: Some(pbjson_types::Any {
+                    type_url: pb::DutchAuction::type_url(),
+                    value: auction.encode_to_vec().into(),
+                }),
+                positions: positions.iter().cloned().map(Into::into).collect(),
+            },
+            None => AuctionStateByIdResponse::default(),
+        };
+        Ok(tonic::Response::new(response))
+    }
+
+    type AuctionStateByIdsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<AuctionStateByIdsResponse, tonic::Status>> + Send>,
+    >;
+
+    async fn auction_state_by_ids(
+        &self,
+        request: tonic::Request<AuctionStateByIdsRequest>,
+    ) -> Result<tonic::Response<Self::AuctionStateByIdsStream>, Status> {
+        let mut responses = Vec::new();
+        for id in request.into_inner().id {
+            let id = parse_id(Some(id))?;
+            responses.extend(self.state_by_ids_response(id).map(Ok));
+        }
+        Ok(tonic::Response::new(
+            futures::stream::iter(responses).boxed(),
+        ))
+    }
+}
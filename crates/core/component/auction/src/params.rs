@@ -2,30 +2,48 @@ use penumbra_sdk_proto::core::component::auction::v1 as pb;
 use penumbra_sdk_proto::DomainType;
 use serde::{Deserialize, Serialize};
 
+/// The largest step count that a Dutch auction can be scheduled with,
+/// regardless of the chain parameters.
+pub const MAX_AUCTION_STEP_COUNT: u64 = 255;
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(try_from = "pb::AuctionParameters", into = "pb::AuctionParameters")]
-pub struct AuctionParameters {}
+pub struct AuctionParameters {
+    /// The maximum number of steps a Dutch auction can be scheduled with.
+    pub max_step_count: u64,
+}
 
 impl DomainType for AuctionParameters {
     type Proto = pb::AuctionParameters;
 }
 
 impl From<AuctionParameters> for pb::AuctionParameters {
-    fn from(_: AuctionParameters) -> Self {
-        pb::AuctionParameters {}
+    fn from(params: AuctionParameters) -> Self {
+        pb::AuctionParameters {
+            max_step_count: params.max_step_count,
+        }
     }
 }
 
 impl TryFrom<pb::AuctionParameters> for AuctionParameters {
     type Error = anyhow::Error;
 
-    fn try_from(_: pb::AuctionParameters) -> anyhow::Result<Self> {
-        Ok(AuctionParameters {})
+    fn try_from(msg: pb::AuctionParameters) -> anyhow::Result<Self> {
+        // Parameters recorded before `max_step_count` existed leave it unset,
+        // in which case we fall back to the protocol maximum.
+        let max_step_count = match msg.max_step_count {
+            0 => MAX_AUCTION_STEP_COUNT,
+            n => n,
+        };
+
+        Ok(AuctionParameters { max_step_count })
     }
 }
 
 impl Default for AuctionParameters {
     fn default() -> Self {
-        AuctionParameters {}
+        AuctionParameters {
+            max_step_count: MAX_AUCTION_STEP_COUNT,
+        }
     }
 }
@@ -11,7 +11,9 @@ mod view;
 pub mod proof;
 
 pub use action::{Body, Swap};
-pub use ciphertext::SwapCiphertext;
+pub use ciphertext::{
+    CommitmentMismatch, SwapCiphertext, SwapCiphertextBatch, SwapDecryptionError,
+};
 pub use payload::SwapPayload;
 pub use plaintext::{SwapPlaintext, SwapPlaintextVar};
 pub use plan::SwapPlan;
@@ -9,31 +9,185 @@ use super::{SwapPlaintext, SWAP_CIPHERTEXT_BYTES, SWAP_LEN_BYTES};
 pub struct SwapCiphertext(pub [u8; SWAP_CIPHERTEXT_BYTES]);
 
 impl SwapCiphertext {
+    /// Decrypt the swap, and check that the plaintext commits to the supplied swap commitment.
+    ///
+    /// The payload key is derived from the commitment, but decryption alone doesn't guarantee
+    /// that the plaintext is the one the commitment binds to. If it isn't, the returned error
+    /// is a [`SwapDecryptionError::CommitmentMismatch`].
     pub fn decrypt(
         &self,
         ovk: &OutgoingViewingKey,
         commitment: note::StateCommitment,
-    ) -> Result<SwapPlaintext> {
+    ) -> Result<SwapPlaintext, SwapDecryptionError> {
         let payload_key = PayloadKey::derive_swap(ovk, commitment);
-        self.decrypt_with_payload_key(&payload_key)
+        let plaintext = self.decrypt_with_payload_key(&payload_key)?;
+        check_commitment(plaintext, commitment)
     }
 
-    pub fn decrypt_with_payload_key(&self, payload_key: &PayloadKey) -> Result<SwapPlaintext> {
-        let swap_ciphertext = self.0;
-        let decryption_result = payload_key
-            .decrypt_swap(swap_ciphertext.to_vec())
-            .map_err(|_| anyhow::anyhow!("unable to decrypt swap ciphertext"))?;
+    pub fn decrypt_with_payload_key(
+        &self,
+        payload_key: &PayloadKey,
+    ) -> Result<SwapPlaintext, SwapDecryptionError> {
+        decrypt_swap_bytes(payload_key, &self.0)
+    }
+
+    /// Lazily decrypt each of the supplied ciphertexts, along with their swap commitments.
+    ///
+    /// The payload key of every ciphertext is derived from its commitment, and each item
+    /// is decrypted as with [`SwapCiphertext::decrypt`]. A ciphertext that fails to decrypt
+    /// yields an error for that item only, so scanning code can keep going.
+    pub fn decrypt_each<'a, I>(
+        ovk: &'a OutgoingViewingKey,
+        ciphertexts: I,
+    ) -> impl Iterator<Item = Result<SwapPlaintext, SwapDecryptionError>> + 'a
+    where
+        I: IntoIterator<Item = (SwapCiphertext, note::StateCommitment)>,
+        I::IntoIter: 'a,
+    {
+        ciphertexts
+            .into_iter()
+            .map(move |(ciphertext, commitment)| ciphertext.decrypt(ovk, commitment))
+    }
+}
 
-        // TODO: encapsulate plaintext encoding by making this a
-        // pub(super) parse_decryption method on SwapPlaintext
-        // and removing the TryFrom impls
-        let plaintext: [u8; SWAP_LEN_BYTES] = decryption_result
+/// The error returned when a swap ciphertext decrypts to a plaintext that doesn't commit to
+/// the swap commitment it was keyed to.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("decrypted swap plaintext commits to {actual:?}, expected {expected:?}")]
+pub struct CommitmentMismatch {
+    pub expected: note::StateCommitment,
+    pub actual: note::StateCommitment,
+}
+
+/// The ways in which decrypting a [`SwapCiphertext`] can fail.
+///
+/// This converts into an [`anyhow::Error`], so callers returning `anyhow::Result` can keep
+/// using `?`.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SwapDecryptionError {
+    /// The ciphertext was not encrypted under the payload key, e.g. because it is
+    /// addressed to a different outgoing viewing key.
+    #[error("unable to decrypt swap ciphertext")]
+    KeyMismatch,
+    /// The ciphertext decrypted, but not to a plaintext of the expected length.
+    #[error("swap decryption result has length {got}, expected {expected}")]
+    LengthMismatch { expected: usize, got: usize },
+    /// The plaintext bytes don't encode a valid swap plaintext.
+    #[error("unable to convert swap plaintext bytes into SwapPlaintext")]
+    Parse,
+    /// The plaintext doesn't commit to the swap commitment it was keyed to.
+    #[error(transparent)]
+    CommitmentMismatch(#[from] CommitmentMismatch),
+}
+
+fn check_commitment(
+    plaintext: SwapPlaintext,
+    expected: note::StateCommitment,
+) -> Result<SwapPlaintext, SwapDecryptionError> {
+    let actual = plaintext.commit();
+    if actual != expected {
+        return Err(CommitmentMismatch { expected, actual }.into());
+    }
+    Ok(plaintext)
+}
+
+fn decrypt_swap_bytes(
+    payload_key: &PayloadKey,
+    swap_ciphertext: &[u8],
+) -> Result<SwapPlaintext, SwapDecryptionError> {
+    let decryption_result = payload_key
+        .decrypt_swap(swap_ciphertext.to_vec())
+        .map_err(|_| SwapDecryptionError::KeyMismatch)?;
+
+    parse_swap_plaintext(&decryption_result)
+}
+
+fn parse_swap_plaintext(decryption_result: &[u8]) -> Result<SwapPlaintext, SwapDecryptionError> {
+    // TODO: encapsulate plaintext encoding by making this a
+    // pub(super) parse_decryption method on SwapPlaintext
+    // and removing the TryFrom impls
+    let plaintext: [u8; SWAP_LEN_BYTES] =
+        decryption_result
             .try_into()
-            .map_err(|_| anyhow::anyhow!("swap decryption result did not fit in plaintext len"))?;
+            .map_err(|_| SwapDecryptionError::LengthMismatch {
+                expected: SWAP_LEN_BYTES,
+                got: decryption_result.len(),
+            })?;
+
+    plaintext.try_into().map_err(|_| SwapDecryptionError::Parse)
+}
+
+/// A batch of swap ciphertexts, stored contiguously.
+///
+/// This avoids allocating each ciphertext separately when scanning many swaps,
+/// e.g. in a view server processing a compact block.
+#[derive(Debug, Clone, Default)]
+pub struct SwapCiphertextBatch {
+    bytes: Vec<u8>,
+}
 
-        plaintext.try_into().map_err(|_| {
-            anyhow::anyhow!("unable to convert swap plaintext bytes into SwapPlaintext")
-        })
+impl SwapCiphertextBatch {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(capacity * SWAP_CIPHERTEXT_BYTES),
+        }
+    }
+
+    pub fn push(&mut self, ciphertext: &SwapCiphertext) {
+        self.bytes.extend_from_slice(&ciphertext.0);
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len() / SWAP_CIPHERTEXT_BYTES
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Trial-decrypt every ciphertext in the batch with the supplied outgoing viewing key.
+    ///
+    /// The `commitments` are the swap commitments of the ciphertexts, in the same order. The
+    /// result has one entry per ciphertext, checked as with [`SwapCiphertext::decrypt`]. Unlike
+    /// the per-item path, which copies each ciphertext into a fresh allocation to decrypt it,
+    /// every ciphertext is decrypted in place in a single reused buffer.
+    pub fn decrypt_all(
+        &self,
+        ovk: &OutgoingViewingKey,
+        commitments: &[note::StateCommitment],
+    ) -> Result<Vec<Result<SwapPlaintext, SwapDecryptionError>>> {
+        anyhow::ensure!(
+            commitments.len() == self.len(),
+            "expected {} swap commitments, got {}",
+            self.len(),
+            commitments.len()
+        );
+
+        let mut buffer = Vec::with_capacity(SWAP_CIPHERTEXT_BYTES);
+        Ok(self
+            .bytes
+            .chunks_exact(SWAP_CIPHERTEXT_BYTES)
+            .zip(commitments)
+            .map(|(ciphertext, commitment)| {
+                let payload_key = PayloadKey::derive_swap(ovk, *commitment);
+                buffer.clear();
+                buffer.extend_from_slice(ciphertext);
+                payload_key
+                    .decrypt_swap_in_place(&mut buffer)
+                    .map_err(|_| SwapDecryptionError::KeyMismatch)?;
+                check_commitment(parse_swap_plaintext(&buffer)?, *commitment)
+            })
+            .collect())
+    }
+}
+
+impl<'a> FromIterator<&'a SwapCiphertext> for SwapCiphertextBatch {
+    fn from_iter<I: IntoIterator<Item = &'a SwapCiphertext>>(iter: I) -> Self {
+        let mut batch = SwapCiphertextBatch::default();
+        for ciphertext in iter {
+            batch.push(ciphertext);
+        }
+        batch
     }
 }
 
@@ -49,6 +203,246 @@ impl TryFrom<&[u8]> for SwapCiphertext {
     type Error = anyhow::Error;
 
     fn try_from(slice: &[u8]) -> Result<SwapCiphertext, Self::Error> {
-        Ok(SwapCiphertext(slice[..].try_into()?))
+        anyhow::ensure!(
+            slice.len() == SWAP_CIPHERTEXT_BYTES,
+            "swap ciphertext must be {} bytes, got {}",
+            SWAP_CIPHERTEXT_BYTES,
+            slice.len()
+        );
+        Ok(SwapCiphertext(slice.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{swap::SwapPayload, TradingPair};
+    use penumbra_sdk_asset::{asset, Value};
+    use penumbra_sdk_fee::Fee;
+    use penumbra_sdk_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+
+    /// Encrypt `count` swaps to a fresh outgoing viewing key, returning the key
+    /// along with the swap plaintexts and payloads.
+    fn encrypted_swaps(count: u64) -> (OutgoingViewingKey, Vec<SwapPlaintext>, Vec<SwapPayload>) {
+        let mut rng = OsRng;
+        let seed_phrase = SeedPhrase::generate(rng);
+        let sk = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let fvk = sk.full_viewing_key();
+        let (dest, _dtk_d) = fvk.incoming().payment_address(0u32.into());
+
+        let cache = asset::Cache::with_known_assets();
+        let upenumbra = cache.get_unit("upenumbra").unwrap().id();
+        let trading_pair = TradingPair::new(upenumbra, cache.get_unit("nala").unwrap().id());
+
+        let swaps: Vec<SwapPlaintext> = (1..=count)
+            .map(|delta_1| {
+                SwapPlaintext::new(
+                    &mut rng,
+                    trading_pair,
+                    delta_1.into(),
+                    0u64.into(),
+                    Fee(Value {
+                        amount: 0u64.into(),
+                        asset_id: upenumbra,
+                    }),
+                    dest.clone(),
+                )
+            })
+            .collect();
+        let payloads = swaps
+            .iter()
+            .map(|swap| swap.encrypt(fvk.outgoing()))
+            .collect();
+
+        (fvk.outgoing().clone(), swaps, payloads)
+    }
+
+    #[test]
+    fn batch_decryption_matches_per_item_decryption() {
+        let (ovk, swaps, mut payloads) = encrypted_swaps(3);
+        // A swap encrypted to someone else can't be decrypted.
+        let (_, _, foreign) = encrypted_swaps(1);
+        payloads.extend(foreign);
+
+        let batch: SwapCiphertextBatch = payloads
+            .iter()
+            .map(|payload| &payload.encrypted_swap)
+            .collect();
+        let commitments: Vec<_> = payloads.iter().map(|payload| payload.commitment).collect();
+        assert_eq!(batch.len(), 4);
+
+        let decrypted = batch.decrypt_all(&ovk, &commitments).unwrap();
+        for (payload, plaintext) in payloads.iter().zip(decrypted.iter()) {
+            let expected = payload.encrypted_swap.decrypt(&ovk, payload.commitment);
+            assert_eq!(plaintext.as_ref().ok(), expected.as_ref().ok());
+        }
+        for (plaintext, swap) in decrypted.iter().zip(swaps.iter()) {
+            assert_eq!(plaintext.as_ref().unwrap(), swap);
+        }
+        assert!(matches!(
+            decrypted[3],
+            Err(SwapDecryptionError::KeyMismatch)
+        ));
+
+        // The commitments must line up with the ciphertexts.
+        assert!(batch.decrypt_all(&ovk, &commitments[..3]).is_err());
+    }
+
+    #[test]
+    fn each_ciphertext_is_decrypted_independently() {
+        let (ovk, swaps, mut payloads) = encrypted_swaps(2);
+        let (_, _, foreign) = encrypted_swaps(1);
+        payloads.insert(1, foreign[0].clone());
+
+        let results: Vec<_> = SwapCiphertext::decrypt_each(
+            &ovk,
+            payloads
+                .iter()
+                .map(|payload| (payload.encrypted_swap.clone(), payload.commitment)),
+        )
+        .collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap(), &swaps[0]);
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap(), &swaps[1]);
+    }
+
+    #[test]
+    fn batch_decryption_scales() {
+        let (ovk, swaps, payloads) = encrypted_swaps(256);
+
+        let mut batch = SwapCiphertextBatch::with_capacity(payloads.len());
+        for payload in &payloads {
+            batch.push(&payload.encrypted_swap);
+        }
+        let commitments: Vec<_> = payloads.iter().map(|payload| payload.commitment).collect();
+
+        let start = std::time::Instant::now();
+        let batched = batch.decrypt_all(&ovk, &commitments).unwrap();
+        let batch_elapsed = start.elapsed();
+
+        let start = std::time::Instant::now();
+        let per_item: Vec<_> = SwapCiphertext::decrypt_each(
+            &ovk,
+            payloads
+                .iter()
+                .map(|payload| (payload.encrypted_swap.clone(), payload.commitment)),
+        )
+        .collect();
+        let per_item_elapsed = start.elapsed();
+        tracing::debug!(?batch_elapsed, ?per_item_elapsed, "decrypted 256 swaps");
+
+        assert_eq!(batched.len(), swaps.len());
+        for ((batched, per_item), swap) in batched.iter().zip(per_item.iter()).zip(swaps.iter()) {
+            assert_eq!(batched.as_ref().unwrap(), swap);
+            assert_eq!(per_item.as_ref().unwrap(), swap);
+        }
+    }
+
+    #[test]
+    fn decryption_rejects_tampered_plaintext() {
+        let (ovk, swaps, payloads) = encrypted_swaps(1);
+        let (swap, payload) = (&swaps[0], &payloads[0]);
+
+        assert_eq!(swap.commit(), payload.commitment);
+        assert_eq!(
+            payload
+                .encrypted_swap
+                .decrypt(&ovk, payload.commitment)
+                .unwrap(),
+            *swap
+        );
+
+        // Encrypt a tampered plaintext under the key of the original commitment.
+        let mut tampered = swap.clone();
+        tampered.delta_1_i = 1_000u64.into();
+        let payload_key = PayloadKey::derive_swap(&ovk, payload.commitment);
+        let bytes: [u8; SWAP_LEN_BYTES] = tampered.into();
+        let ciphertext: [u8; SWAP_CIPHERTEXT_BYTES] =
+            payload_key.encrypt_swap(bytes.to_vec()).try_into().unwrap();
+        let ciphertext = SwapCiphertext(ciphertext);
+
+        assert!(ciphertext.decrypt_with_payload_key(&payload_key).is_ok());
+        assert!(ciphertext.decrypt(&ovk, payload.commitment).is_err());
+    }
+
+    #[test]
+    fn decryption_reports_commitment_mismatch() {
+        let (ovk, swaps, payloads) = encrypted_swaps(2);
+
+        // Re-encrypt the second swap under the key of the first swap's commitment.
+        let expected = payloads[0].commitment;
+        let payload_key = PayloadKey::derive_swap(&ovk, expected);
+        let bytes: [u8; SWAP_LEN_BYTES] = (&swaps[1]).into();
+        let ciphertext =
+            SwapCiphertext(payload_key.encrypt_swap(bytes.to_vec()).try_into().unwrap());
+
+        let error = ciphertext.decrypt(&ovk, expected).unwrap_err();
+        let SwapDecryptionError::CommitmentMismatch(mismatch) = &error else {
+            panic!("expected a commitment mismatch, got {error:?}");
+        };
+        assert_eq!(mismatch.expected, expected);
+        assert_eq!(mismatch.actual, payloads[1].commitment);
+
+        // Callers using `anyhow` can still recover the mismatch.
+        let error = anyhow::Error::from(error);
+        assert!(error.downcast_ref::<SwapDecryptionError>().is_some());
+
+        let batch: SwapCiphertextBatch = [&ciphertext].into_iter().collect();
+        assert!(matches!(
+            batch.decrypt_all(&ovk, &[expected]).unwrap()[..],
+            [Err(SwapDecryptionError::CommitmentMismatch(_))]
+        ));
+    }
+
+    #[test]
+    fn decryption_errors_are_typed() {
+        let (ovk, _, payloads) = encrypted_swaps(1);
+        let commitment = payloads[0].commitment;
+        let payload_key = PayloadKey::derive_swap(&ovk, commitment);
+
+        // A swap encrypted to someone else.
+        let (_, _, foreign) = encrypted_swaps(1);
+        assert!(matches!(
+            foreign[0].encrypted_swap.decrypt(&ovk, commitment),
+            Err(SwapDecryptionError::KeyMismatch)
+        ));
+
+        // A plaintext that is too short.
+        let short = payload_key.encrypt_swap(vec![0u8; SWAP_LEN_BYTES - 1]);
+        let error = decrypt_swap_bytes(&payload_key, &short).unwrap_err();
+        assert!(matches!(
+            error,
+            SwapDecryptionError::LengthMismatch { expected, got }
+                if expected == SWAP_LEN_BYTES && got == SWAP_LEN_BYTES - 1
+        ));
+
+        // A plaintext of the right length that isn't a valid swap.
+        let ciphertext = SwapCiphertext(
+            payload_key
+                .encrypt_swap(vec![0xff; SWAP_LEN_BYTES])
+                .try_into()
+                .unwrap(),
+        );
+        assert!(matches!(
+            ciphertext.decrypt(&ovk, commitment),
+            Err(SwapDecryptionError::Parse)
+        ));
+    }
+
+    #[test]
+    fn ciphertext_length_is_checked() {
+        let bytes = [0u8; SWAP_CIPHERTEXT_BYTES + 1];
+        assert!(SwapCiphertext::try_from(&bytes[..SWAP_CIPHERTEXT_BYTES]).is_ok());
+
+        for len in [SWAP_CIPHERTEXT_BYTES - 1, SWAP_CIPHERTEXT_BYTES + 1] {
+            let error = SwapCiphertext::try_from(&bytes[..len]).unwrap_err();
+            let message = error.to_string();
+            assert!(message.contains(&SWAP_CIPHERTEXT_BYTES.to_string()));
+            assert!(message.contains(&len.to_string()));
+        }
     }
 }
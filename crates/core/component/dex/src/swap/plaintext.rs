@@ -121,6 +121,12 @@ impl SwapPlaintext {
         StateCommitment(inner)
     }
 
+    /// Recompute the swap commitment of this plaintext, the native counterpart
+    /// of [`SwapPlaintextVar::commit`].
+    pub fn commit(&self) -> StateCommitment {
+        self.swap_commitment()
+    }
+
     pub fn diversified_generator(&self) -> &decaf377::Element {
         self.claim_address.diversified_generator()
     }
@@ -129,6 +135,12 @@ impl SwapPlaintext {
         self.claim_address.transmission_key()
     }
 
+    /// Whether the outputs of this swap will be claimable by the supplied address, so that a
+    /// wallet controlling several addresses can route the swap to the right account.
+    pub fn is_addressed_to(&self, address: &Address) -> bool {
+        &self.claim_address == address
+    }
+
     pub fn encrypt(&self, ovk: &OutgoingViewingKey) -> SwapPayload {
         let commitment = self.swap_commitment();
         let key = PayloadKey::derive_swap(ovk, commitment);
@@ -466,4 +478,30 @@ mod tests {
 
         assert_eq!(plaintext, swap);
     }
+
+    #[test]
+    fn swap_is_addressed_to_its_claim_address() {
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let sk = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let ivk = sk.full_viewing_key().incoming();
+        let (claim_address, _) = ivk.payment_address(0u32.into());
+        let (other_address, _) = ivk.payment_address(1u32.into());
+
+        let cache = asset::Cache::with_known_assets();
+        let upenumbra = cache.get_unit("upenumbra").unwrap().id();
+        let swap = SwapPlaintext::new(
+            &mut OsRng,
+            TradingPair::new(upenumbra, cache.get_unit("nala").unwrap().id()),
+            100u64.into(),
+            0u64.into(),
+            Fee(Value {
+                amount: 0u64.into(),
+                asset_id: upenumbra,
+            }),
+            claim_address.clone(),
+        );
+
+        assert!(swap.is_addressed_to(&claim_address));
+        assert!(!swap.is_addressed_to(&other_address));
+    }
 }
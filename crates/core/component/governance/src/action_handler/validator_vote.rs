@@ -80,13 +80,16 @@ impl ActionHandler for ValidatorVote {
 
         // Emergency proposals are passed immediately after receiving +1/3 of
         // validator votes. These include the eponymous `Emergency` proposal but
-        // also `IbcFreeze` and `IbcUnfreeze`.
+        // also `EmergencyUpgrade`, `IbcFreeze` and `IbcUnfreeze`.
         let proposal_payload = state
             .proposal_payload(*proposal)
             .await?
             .expect("proposal missing payload");
 
-        if proposal_payload.is_emergency() || proposal_payload.is_ibc_freeze() {
+        if proposal_payload.is_emergency()
+            || proposal_payload.is_emergency_upgrade()
+            || proposal_payload.is_ibc_freeze()
+        {
             tracing::debug!(proposal = %proposal, "detected an emergency-tier proposal, checking pass conditions");
             let tally = state.current_tally(*proposal).await?;
             let total_voting_power = state
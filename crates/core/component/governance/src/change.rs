@@ -1,7 +1,7 @@
-use std::str::FromStr;
+use std::{collections::BTreeSet, str::FromStr};
 
 use anyhow::Context;
-use penumbra_sdk_proto::{core::component::governance::v1 as pb, DomainType};
+use penumbra_sdk_proto::{core::component::governance::v1 as pb, DomainType, Message};
 use serde::{Deserialize, Serialize};
 
 /// An encoded parameter.
@@ -55,6 +55,22 @@ impl From<EncodedParameter> for pb::EncodedParameter {
     }
 }
 
+/// The names of the parameter groups of the app parameters, as they appear in the proto JSON
+/// encoding used by [`EncodedParameter::component`].
+pub const APP_PARAMETER_COMPONENTS: [&str; 11] = [
+    "sctParams",
+    "communityPoolParams",
+    "governanceParams",
+    "ibcParams",
+    "stakeParams",
+    "feeParams",
+    "distributionsParams",
+    "fundingParams",
+    "shieldedPoolParams",
+    "dexParams",
+    "auctionParams",
+];
+
 /// A set of changes to the app parameters.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(
@@ -107,6 +123,25 @@ impl From<ParameterChange> for pb::proposal::ParameterChange {
 }
 
 impl ParameterChange {
+    /// The components whose parameters are modified by this change.
+    ///
+    /// A parameter is modified unless the change sets it to the value required by a
+    /// precondition on the same parameter, as in the templates from
+    /// [`ParameterChange::encode_parameters`].
+    pub fn modified_components(&self) -> BTreeSet<&str> {
+        self.changes
+            .iter()
+            .filter(|change| {
+                !self.preconditions.iter().any(|precondition| {
+                    precondition.component == change.component
+                        && precondition.key == change.key
+                        && precondition.value == change.value
+                })
+            })
+            .map(|change| change.component.as_str())
+            .collect()
+    }
+
     /// Generates a set of encoded parameters for the given object.
     ///
     /// This is useful for generating template changes.
@@ -178,6 +213,100 @@ impl ParameterChange {
     }
 }
 
+/// Review helpers for the [`pb::ChangedAppParameters`] carried in the deprecated `old_parameters`
+/// and `new_parameters` fields of parameter change proposals.
+pub trait ChangedAppParametersExt {
+    /// Describe each parameter that differs between `self` and `other`, as lines like
+    /// `"fee_params.gas_price: 100 -> 150"`.
+    ///
+    /// Parameter groups present on both sides are compared field by field, while groups present
+    /// on only one side are reported as added or removed. Fields that are left at their default
+    /// value are rendered as `default`.
+    fn diff(&self, other: &pb::ChangedAppParameters) -> Vec<String>;
+
+    /// Computes a content hash of this set of parameters, suitable for content-addressing
+    /// enacted parameter sets in storage or audit logs.
+    ///
+    /// The hash is computed over the proto encoding, which writes the parameter groups in field
+    /// number order, so equal sets hash to the same value however their groups were filled in.
+    fn content_hash(&self) -> [u8; 32];
+}
+
+impl ChangedAppParametersExt for pb::ChangedAppParameters {
+    fn diff(&self, other: &pb::ChangedAppParameters) -> Vec<String> {
+        // The proto JSON encoding omits unset groups and default fields, which gives a uniform
+        // way to compare each of the parameter groups.
+        let groups = |parameters: &pb::ChangedAppParameters| match serde_json::to_value(parameters)
+        {
+            Ok(serde_json::Value::Object(groups)) => groups,
+            _ => serde_json::Map::new(),
+        };
+        let render = |value: Option<&serde_json::Value>| match value {
+            None => "default".to_string(),
+            Some(serde_json::Value::String(value)) => value.clone(),
+            Some(value) => value.to_string(),
+        };
+        let (old, new) = (groups(self), groups(other));
+
+        let mut changes = Vec::new();
+        let names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+        for name in names {
+            let group = to_snake_case(name);
+            match (old.get(name), new.get(name)) {
+                (Some(old_group), Some(new_group)) => {
+                    let empty = serde_json::Map::new();
+                    let old_fields = old_group.as_object().unwrap_or(&empty);
+                    let new_fields = new_group.as_object().unwrap_or(&empty);
+                    let fields: BTreeSet<&String> =
+                        old_fields.keys().chain(new_fields.keys()).collect();
+                    for field in fields {
+                        let (old_value, new_value) = (old_fields.get(field), new_fields.get(field));
+                        if old_value != new_value {
+                            changes.push(format!(
+                                "{group}.{}: {} -> {}",
+                                to_snake_case(field),
+                                render(old_value),
+                                render(new_value)
+                            ));
+                        }
+                    }
+                }
+                (Some(_), None) => changes.push(format!("{group}: removed")),
+                (None, Some(_)) => changes.push(format!("{group}: added")),
+                (None, None) => {}
+            }
+        }
+        changes
+    }
+
+    fn content_hash(&self) -> [u8; 32] {
+        let hash = blake2b_simd::Params::default()
+            .personal(b"penumbra_paramch")
+            .hash_length(32)
+            .to_state()
+            .update(&self.encode_to_vec())
+            .finalize();
+
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(hash.as_bytes());
+        bytes
+    }
+}
+
+/// Convert a camelCase proto JSON name back to the snake_case name of the proto field.
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            snake.push('_');
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
 fn get_component<'a>(
     app_parameters: &'a mut serde_json::Value,
     change: &EncodedParameter,
@@ -381,6 +510,43 @@ mod tests {
         assert!(new_gov_parameters.is_err());
     }
 
+    #[test]
+    fn content_hash_ignores_construction_order() {
+        use penumbra_sdk_proto::core::component::sct::v1::SctParameters;
+
+        let governance_params = pb::GovernanceParameters {
+            proposal_voting_blocks: 17281,
+            ..Default::default()
+        };
+        let sct_params = SctParameters {
+            epoch_duration: 100,
+        };
+
+        let mut governance_first = pb::ChangedAppParameters {
+            governance_params: Some(governance_params.clone()),
+            ..Default::default()
+        };
+        governance_first.sct_params = Some(sct_params.clone());
+
+        let mut sct_first = pb::ChangedAppParameters {
+            sct_params: Some(sct_params),
+            ..Default::default()
+        };
+        sct_first.governance_params = Some(governance_params);
+
+        assert_eq!(governance_first.content_hash(), sct_first.content_hash());
+
+        let mut changed = sct_first.clone();
+        changed.sct_params = Some(SctParameters {
+            epoch_duration: 101,
+        });
+        assert_ne!(sct_first.content_hash(), changed.content_hash());
+        assert_ne!(
+            sct_first.content_hash(),
+            pb::ChangedAppParameters::default().content_hash()
+        );
+    }
+
     #[test]
     fn preconditions_prevent_applying_changes() {
         let old_parameters_raw: serde_json::Value =
@@ -419,4 +585,42 @@ mod tests {
         assert!(satisfied_result.is_ok());
         assert!(unsatisfied_result.is_err());
     }
+
+    #[test]
+    fn changed_app_parameters_diff() {
+        use penumbra_sdk_proto::core::component::{dex::v1 as pb_dex, sct::v1 as pb_sct};
+
+        let old = pb::ChangedAppParameters {
+            sct_params: Some(pb_sct::SctParameters {
+                epoch_duration: 719,
+            }),
+            governance_params: Some(pb::GovernanceParameters {
+                proposal_voting_blocks: 17_280,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(old.diff(&old).is_empty());
+
+        let new = pb::ChangedAppParameters {
+            sct_params: Some(pb_sct::SctParameters {
+                epoch_duration: 800,
+            }),
+            governance_params: Some(pb::GovernanceParameters {
+                proposal_voting_blocks: 20_000,
+                ..Default::default()
+            }),
+            dex_params: Some(pb_dex::DexParameters::default()),
+            ..Default::default()
+        };
+        assert_eq!(
+            old.diff(&new),
+            vec![
+                "dex_params: added".to_string(),
+                "governance_params.proposal_voting_blocks: 17280 -> 20000".to_string(),
+                "sct_params.epoch_duration: 719 -> 800".to_string(),
+            ]
+        );
+        assert_eq!(new.diff(&old)[0], "dex_params: removed");
+    }
 }
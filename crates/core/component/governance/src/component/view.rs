@@ -576,6 +576,14 @@ pub trait StateReadExt: StateRead + penumbra_sdk_stake::StateReadExt {
             .is_some()
     }
 
+    /// Returns whether the scheduling of new auctions was paused by a governance proposal.
+    async fn auctions_paused(&self) -> Result<bool> {
+        Ok(self
+            .get_proto(state_key::auctions_paused())
+            .await?
+            .unwrap_or_default())
+    }
+
     async fn is_chain_halted(&self) -> bool {
         self.nonverifiable_get_proto(state_key::persistent_flags::halt_bit().as_bytes())
             .await
@@ -933,6 +941,20 @@ pub trait StateWriteExt: StateWrite + penumbra_sdk_ibc::component::ConnectionSta
                 let unfrozen_client = client_state.unfrozen();
                 self.put_client(client_id, unfrozen_client);
             }
+            ProposalPayload::EmergencyUpgrade { height } => {
+                let current_height = self.get_block_height().await?;
+                if *height <= current_height {
+                    return Ok(Err(anyhow::anyhow!(
+                        "emergency upgrade height {height} is not after the current height {current_height}"
+                    )));
+                }
+                tracing::info!(target_height = height, "emergency upgrade proposal passed");
+                self.signal_upgrade(*height).await?;
+            }
+            ProposalPayload::PauseAuctions { paused } => {
+                tracing::info!(paused, "pause auctions proposal passed");
+                self.put_auctions_paused(*paused);
+            }
         }
         Ok(Ok(()))
     }
@@ -965,6 +987,11 @@ pub trait StateWriteExt: StateWrite + penumbra_sdk_ibc::component::ConnectionSta
         Ok(())
     }
 
+    /// Pause or resume the scheduling of new auctions.
+    fn put_auctions_paused(&mut self, paused: bool) {
+        self.put_proto(state_key::auctions_paused().to_string(), paused);
+    }
+
     /// Sets the application `halt_bit` to `true`, signaling that
     /// the chain should be halted, and preventing restarts until
     /// a migration is ran.
@@ -24,7 +24,7 @@ pub mod proposal_withdraw;
 pub use proposal_withdraw::ProposalWithdraw;
 
 pub mod proposal;
-pub use proposal::{Proposal, ProposalKind, ProposalPayload};
+pub use proposal::{Proposal, ProposalKind, ProposalMetadata, ProposalPayload, ProposalStatus};
 
 pub mod proposal_nft;
 pub mod proposal_state;
@@ -59,3 +59,4 @@ pub mod genesis;
 pub mod params;
 
 pub mod change;
+pub use change::ChangedAppParametersExt;
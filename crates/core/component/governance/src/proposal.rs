@@ -2,10 +2,23 @@ use anyhow::Context;
 use bytes::Bytes;
 use ibc_types::core::client::ClientId;
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
-use crate::change::ParameterChange;
-use penumbra_sdk_proto::{penumbra::core::component::governance::v1 as pb, DomainType};
+use penumbra_sdk_asset::Value;
+use penumbra_sdk_keys::Address;
+
+use crate::{
+    change::{ParameterChange, APP_PARAMETER_COMPONENTS},
+    proposal_state::State as ProposalState,
+};
+use penumbra_sdk_proto::{
+    penumbra::core::{component::governance::v1 as pb, transaction::v1 as pb_transaction},
+    DomainType, Message,
+};
 
 /// A governance proposal.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -24,6 +37,22 @@ pub struct Proposal {
     pub payload: ProposalPayload,
 }
 
+/// The descriptive fields of a [`Proposal`], without its payload.
+///
+/// This lets storage layers index proposals by their metadata separately from their payloads,
+/// see [`Proposal::split`] and [`Proposal::join`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProposalMetadata {
+    /// The ID number of the proposal.
+    pub id: u64,
+
+    /// A short title describing the intent of the proposal.
+    pub title: String,
+
+    /// A natural-language description of the effect of the proposal and its justification.
+    pub description: String,
+}
+
 /// The protobuf type URL for a transaction plan.
 pub const TRANSACTION_PLAN_TYPE_URL: &str = "/penumbra.core.transaction.v1.TransactionPlan";
 
@@ -73,6 +102,16 @@ impl From<Proposal> for pb::Proposal {
                     client_id: client_id.into(),
                 },
             )),
+            ProposalPayload::PauseAuctions { paused } => {
+                Some(Payload::PauseAuctions(pb::proposal::PauseAuctions {
+                    paused,
+                }))
+            }
+            ProposalPayload::EmergencyUpgrade { height } => {
+                Some(Payload::EmergencyUpgrade(pb::proposal::EmergencyUpgrade {
+                    height,
+                }))
+            }
         };
         proposal.payload = payload;
         proposal
@@ -164,6 +203,12 @@ impl TryFrom<pb::Proposal> for Proposal {
                         client_id: unfreeze_ibc_client.client_id,
                     }
                 }
+                Payload::PauseAuctions(pause_auctions) => ProposalPayload::PauseAuctions {
+                    paused: pause_auctions.paused,
+                },
+                Payload::EmergencyUpgrade(emergency_upgrade) => ProposalPayload::EmergencyUpgrade {
+                    height: emergency_upgrade.height,
+                },
             },
         })
     }
@@ -183,6 +228,24 @@ pub struct ProposalToml {
     pub payload: ProposalPayloadToml,
 }
 
+impl ProposalToml {
+    /// Resolve the relative file paths referenced by the proposal against `dir`, which should be
+    /// the directory of the proposal file, so that the proposal can be converted regardless of
+    /// the current working directory.
+    pub fn resolve_relative_to(mut self, dir: &Path) -> ProposalToml {
+        if let ProposalPayloadToml::CommunityPoolSpend {
+            transaction_file: Some(path),
+            ..
+        } = &mut self.payload
+        {
+            if path.is_relative() {
+                *path = dir.join(&*path);
+            }
+        }
+        self
+    }
+}
+
 impl From<Proposal> for ProposalToml {
     fn from(proposal: Proposal) -> ProposalToml {
         ProposalToml {
@@ -225,6 +288,10 @@ pub enum ProposalKind {
     FreezeIbcClient,
     /// A proposal to unfreeze an IBC client.
     UnfreezeIbcClient,
+    /// A proposal to pause or resume the scheduling of auctions.
+    PauseAuctions,
+    /// An emergency upgrade proposal.
+    EmergencyUpgrade,
 }
 
 impl From<ProposalKind> for pb::ProposalKind {
@@ -237,6 +304,8 @@ impl From<ProposalKind> for pb::ProposalKind {
             ProposalKind::UpgradePlan => pb::ProposalKind::UpgradePlan,
             ProposalKind::FreezeIbcClient => pb::ProposalKind::FreezeIbcClient,
             ProposalKind::UnfreezeIbcClient => pb::ProposalKind::UnfreezeIbcClient,
+            ProposalKind::PauseAuctions => pb::ProposalKind::PauseAuctions,
+            ProposalKind::EmergencyUpgrade => pb::ProposalKind::EmergencyUpgrade,
         }
     }
 }
@@ -254,6 +323,8 @@ impl TryFrom<pb::ProposalKind> for ProposalKind {
             pb::ProposalKind::UpgradePlan => ProposalKind::UpgradePlan,
             pb::ProposalKind::FreezeIbcClient => ProposalKind::FreezeIbcClient,
             pb::ProposalKind::UnfreezeIbcClient => ProposalKind::UnfreezeIbcClient,
+            pb::ProposalKind::PauseAuctions => ProposalKind::PauseAuctions,
+            pb::ProposalKind::EmergencyUpgrade => ProposalKind::EmergencyUpgrade,
         };
         Ok(kind)
     }
@@ -269,11 +340,48 @@ impl FromStr for ProposalKind {
             "parameter_change" => Ok(ProposalKind::ParameterChange),
             "community_pool_spend" => Ok(ProposalKind::CommunityPoolSpend),
             "upgrade_plan" => Ok(ProposalKind::UpgradePlan),
+            "freeze_ibc_client" => Ok(ProposalKind::FreezeIbcClient),
+            "unfreeze_ibc_client" => Ok(ProposalKind::UnfreezeIbcClient),
+            "pause_auctions" => Ok(ProposalKind::PauseAuctions),
+            "emergency_upgrade" => Ok(ProposalKind::EmergencyUpgrade),
             _ => Err(anyhow::anyhow!("invalid proposal kind: {}", s)),
         }
     }
 }
 
+impl ProposalKind {
+    /// A stable, URL-safe identifier for the proposal kind, e.g. for frontend routes.
+    pub fn slug(&self) -> &'static str {
+        match self {
+            ProposalKind::Signaling => "signaling",
+            ProposalKind::Emergency => "emergency",
+            ProposalKind::ParameterChange => "parameter-change",
+            ProposalKind::CommunityPoolSpend => "community-pool-spend",
+            ProposalKind::UpgradePlan => "upgrade-plan",
+            ProposalKind::FreezeIbcClient => "freeze-ibc-client",
+            ProposalKind::UnfreezeIbcClient => "unfreeze-ibc-client",
+            ProposalKind::PauseAuctions => "pause-auctions",
+            ProposalKind::EmergencyUpgrade => "emergency-upgrade",
+        }
+    }
+
+    /// Parse a proposal kind from its [`slug`](ProposalKind::slug).
+    pub fn from_slug(slug: &str) -> anyhow::Result<ProposalKind> {
+        match slug {
+            "signaling" => Ok(ProposalKind::Signaling),
+            "emergency" => Ok(ProposalKind::Emergency),
+            "parameter-change" => Ok(ProposalKind::ParameterChange),
+            "community-pool-spend" => Ok(ProposalKind::CommunityPoolSpend),
+            "upgrade-plan" => Ok(ProposalKind::UpgradePlan),
+            "freeze-ibc-client" => Ok(ProposalKind::FreezeIbcClient),
+            "unfreeze-ibc-client" => Ok(ProposalKind::UnfreezeIbcClient),
+            "pause-auctions" => Ok(ProposalKind::PauseAuctions),
+            "emergency-upgrade" => Ok(ProposalKind::EmergencyUpgrade),
+            _ => Err(anyhow::anyhow!("invalid proposal kind slug: {}", slug)),
+        }
+    }
+}
+
 impl Proposal {
     /// Get the kind of a proposal.
     pub fn kind(&self) -> ProposalKind {
@@ -285,10 +393,333 @@ impl Proposal {
             ProposalPayload::UpgradePlan { .. } => ProposalKind::UpgradePlan,
             ProposalPayload::FreezeIbcClient { .. } => ProposalKind::FreezeIbcClient,
             ProposalPayload::UnfreezeIbcClient { .. } => ProposalKind::UnfreezeIbcClient,
+            ProposalPayload::PauseAuctions { .. } => ProposalKind::PauseAuctions,
+            ProposalPayload::EmergencyUpgrade { .. } => ProposalKind::EmergencyUpgrade,
+        }
+    }
+
+    /// A compact, one-line summary of the proposal's payload, e.g. for listing proposals.
+    pub fn summary(&self) -> String {
+        match &self.payload {
+            ProposalPayload::Signaling {
+                commit: Some(commit),
+            } => {
+                format!("Signaling: commit {commit}")
+            }
+            ProposalPayload::Signaling { commit: None } => "Signaling".to_string(),
+            ProposalPayload::Emergency { halt_chain: true } => {
+                "Emergency: halt the chain".to_string()
+            }
+            ProposalPayload::Emergency { halt_chain: false } => "Emergency".to_string(),
+            ProposalPayload::ParameterChange(change) => match change.modified_components().len() {
+                1 => "Parameter change: 1 parameter group modified".to_string(),
+                groups => format!("Parameter change: {groups} parameter groups modified"),
+            },
+            ProposalPayload::CommunityPoolSpend { transaction_plan } => {
+                match pb_transaction::TransactionPlan::decode(transaction_plan.as_slice()) {
+                    Ok(plan) if plan.actions.len() == 1 => {
+                        "Community Pool spend: 1 action".to_string()
+                    }
+                    Ok(plan) => format!("Community Pool spend: {} actions", plan.actions.len()),
+                    Err(_) => "Community Pool spend: malformed transaction plan".to_string(),
+                }
+            }
+            ProposalPayload::UpgradePlan { height } => format!("Upgrade at height {height}"),
+            ProposalPayload::FreezeIbcClient { client_id } => {
+                format!("Freeze IBC client {client_id}")
+            }
+            ProposalPayload::UnfreezeIbcClient { client_id } => {
+                format!("Unfreeze IBC client {client_id}")
+            }
+            ProposalPayload::PauseAuctions { paused: true } => "Pause auctions".to_string(),
+            ProposalPayload::PauseAuctions { paused: false } => "Resume auctions".to_string(),
+            ProposalPayload::EmergencyUpgrade { height } => {
+                format!("Emergency upgrade at height {height}")
+            }
+        }
+    }
+
+    /// Split the proposal into its metadata and its payload.
+    pub fn split(&self) -> (ProposalMetadata, ProposalPayload) {
+        let metadata = ProposalMetadata {
+            id: self.id,
+            title: self.title.clone(),
+            description: self.description.clone(),
+        };
+        (metadata, self.payload.clone())
+    }
+
+    /// Reassemble a proposal from its metadata and its payload, the inverse of
+    /// [`Proposal::split`].
+    pub fn join(metadata: ProposalMetadata, payload: ProposalPayload) -> Proposal {
+        let ProposalMetadata {
+            id,
+            title,
+            description,
+        } = metadata;
+        Proposal {
+            id,
+            title,
+            description,
+            payload,
+        }
+    }
+
+    /// Construct an emergency proposal that halts the chain as soon as it passes.
+    ///
+    /// The proposal is validated against the same limits as a proposal decoded from its proto
+    /// representation.
+    pub fn emergency_halt(
+        id: u64,
+        title: impl Into<String>,
+        description: impl Into<String>,
+    ) -> anyhow::Result<Proposal> {
+        let proposal = Proposal {
+            id,
+            title: title.into(),
+            description: description.into(),
+            payload: ProposalPayload::Emergency { halt_chain: true },
+        };
+
+        pb::Proposal::from(proposal).try_into()
+    }
+
+    /// Check the proposal for mistakes that would get it rejected by the chain, before submitting
+    /// it.
+    ///
+    /// Besides the limits enforced when decoding a proposal, this checks that signaling commits
+    /// are hex-encoded, that upgrades are planned after `current_height`, that IBC client ids are
+    /// valid, and that Community Pool spends decode into a transaction plan made only of actions
+    /// the chain can build, see [`check_community_pool_spend_action`]. Parameter changes can only
+    /// be checked against the app parameters, see [`validate_proposals`].
+    pub fn validate(&self, current_height: u64) -> anyhow::Result<()> {
+        // Decoding enforces the title and description limits of every kind of proposal.
+        Proposal::try_from(pb::Proposal::from(self.clone()))?;
+
+        match &self.payload {
+            ProposalPayload::Signaling {
+                commit: Some(commit),
+            } => {
+                anyhow::ensure!(
+                    !commit.is_empty() && commit.chars().all(|c| c.is_ascii_hexdigit()),
+                    "signaling proposal commit must be a hex-encoded hash (got: {commit:?})"
+                );
+            }
+            ProposalPayload::Signaling { commit: None }
+            | ProposalPayload::Emergency { .. }
+            | ProposalPayload::ParameterChange(_)
+            | ProposalPayload::PauseAuctions { .. } => {}
+            ProposalPayload::CommunityPoolSpend { transaction_plan } => {
+                let plan = pb_transaction::TransactionPlan::decode(transaction_plan.as_slice())
+                    .context("transaction plan was malformed")?;
+                for action in plan
+                    .actions
+                    .iter()
+                    .filter_map(|action| action.action.as_ref())
+                {
+                    check_community_pool_spend_action(action)?;
+                }
+            }
+            ProposalPayload::UpgradePlan { height }
+            | ProposalPayload::EmergencyUpgrade { height } => {
+                anyhow::ensure!(
+                    *height > current_height,
+                    "upgrade height {height} must be in the future (current height: {current_height})"
+                );
+            }
+            ProposalPayload::FreezeIbcClient { client_id }
+            | ProposalPayload::UnfreezeIbcClient { client_id } => {
+                ClientId::from_str(client_id)
+                    .context("can't decode client id from IBC proposal")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render the proposal as a markdown document, e.g. for cross-posting to a forum.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!(
+            "# Proposal #{}: {}\n\n{}\n\n## Details\n\n",
+            self.id, self.title, self.description
+        );
+
+        match &self.payload {
+            ProposalPayload::Signaling { commit } => {
+                markdown.push_str("- **Kind:** signaling\n");
+                if let Some(commit) = commit {
+                    markdown.push_str(&format!("- **Commit:** `{commit}`\n"));
+                }
+            }
+            ProposalPayload::Emergency { halt_chain } => {
+                markdown.push_str("- **Kind:** emergency\n");
+                markdown.push_str(&format!("- **Halt chain:** {halt_chain}\n"));
+            }
+            ProposalPayload::ParameterChange(change) => {
+                markdown.push_str("- **Kind:** parameter change\n");
+                markdown.push_str("\n### Changes\n\n");
+                for parameter in &change.changes {
+                    markdown.push_str(&format!(
+                        "- `{}.{}` = `{}`\n",
+                        parameter.component, parameter.key, parameter.value
+                    ));
+                }
+                if !change.preconditions.is_empty() {
+                    markdown.push_str("\n### Preconditions\n\n");
+                    for parameter in &change.preconditions {
+                        markdown.push_str(&format!(
+                            "- `{}.{}` = `{}`\n",
+                            parameter.component, parameter.key, parameter.value
+                        ));
+                    }
+                }
+            }
+            ProposalPayload::CommunityPoolSpend { transaction_plan } => {
+                markdown.push_str("- **Kind:** community pool spend\n");
+                markdown.push_str(&format!(
+                    "- **Transaction plan:** {} bytes\n",
+                    transaction_plan.len()
+                ));
+            }
+            ProposalPayload::UpgradePlan { height } => {
+                markdown.push_str("- **Kind:** upgrade plan\n");
+                markdown.push_str(&format!("- **Upgrade height:** {height}\n"));
+            }
+            ProposalPayload::FreezeIbcClient { client_id } => {
+                markdown.push_str("- **Kind:** freeze IBC client\n");
+                markdown.push_str(&format!("- **Client ID:** `{client_id}`\n"));
+            }
+            ProposalPayload::UnfreezeIbcClient { client_id } => {
+                markdown.push_str("- **Kind:** unfreeze IBC client\n");
+                markdown.push_str(&format!("- **Client ID:** `{client_id}`\n"));
+            }
+            ProposalPayload::PauseAuctions { paused } => {
+                markdown.push_str("- **Kind:** pause auctions\n");
+                markdown.push_str(&format!("- **Paused:** {paused}\n"));
+            }
+            ProposalPayload::EmergencyUpgrade { height } => {
+                markdown.push_str("- **Kind:** emergency upgrade\n");
+                markdown.push_str(&format!("- **Upgrade height:** {height}\n"));
+            }
         }
+
+        markdown
     }
 }
 
+/// Check that an action may be part of the transaction plan of a Community Pool spend proposal.
+///
+/// The plan is built by the chain itself once the proposal passes, so it can't contain actions
+/// that need proving or authorization, actions whose outputs can't be claimed, or actions that
+/// manipulate other proposals.
+pub fn check_community_pool_spend_action(
+    action: &pb_transaction::action_plan::Action,
+) -> anyhow::Result<()> {
+    use pb_transaction::action_plan::Action;
+
+    match action {
+        Action::Spend(_)
+        | Action::Output(_)
+        | Action::Swap(_)
+        | Action::SwapClaim(_)
+        | Action::DelegatorVote(_)
+        | Action::UndelegateClaim(_) => {
+            anyhow::bail!("invalid action in Community Pool spend proposal (would require proving)")
+        }
+        Action::Delegate(_) | Action::Undelegate(_) => {
+            anyhow::bail!(
+                "invalid action in Community Pool spend proposal (can't claim outputs of undelegation)"
+            )
+        }
+        Action::ProposalSubmit(_)
+        | Action::ProposalWithdraw(_)
+        | Action::ProposalDepositClaim(_) => {
+            anyhow::bail!(
+                "invalid action in Community Pool spend proposal (not allowed to manipulate proposals from within proposals)"
+            )
+        }
+        Action::PositionRewardClaim(_) => {
+            anyhow::bail!("PositionRewardClaim is deprecated and unsupported")
+        }
+        Action::ValidatorDefinition(_)
+        | Action::IbcRelayAction(_)
+        | Action::ValidatorVote(_)
+        | Action::PositionOpen(_)
+        | Action::PositionClose(_)
+        | Action::PositionWithdraw(_)
+        | Action::CommunityPoolSpend(_)
+        | Action::CommunityPoolOutput(_)
+        | Action::CommunityPoolDeposit(_)
+        | Action::Ics20Withdrawal(_)
+        | Action::ActionDutchAuctionSchedule(_)
+        | Action::ActionDutchAuctionEnd(_)
+        | Action::ActionDutchAuctionWithdraw(_) => Ok(()),
+    }
+}
+
+/// A proposal, along with its state and the height at which its voting period ends, as
+/// recorded by the chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProposalStatus {
+    pub proposal: Proposal,
+    pub state: ProposalState,
+    pub voting_end: u64,
+}
+
+impl ProposalStatus {
+    /// Returns whether the proposal has concluded by `current_height`, e.g. to separate
+    /// active proposals from historical ones.
+    ///
+    /// A proposal concludes at the end of its voting period, or earlier if it was finished
+    /// ahead of time, as emergency proposals are once enough validators voted for them.
+    /// Withdrawn proposals are still voted on until the end of their voting period.
+    pub fn is_concluded(&self, current_height: u64) -> bool {
+        match self.state {
+            ProposalState::Finished { .. } | ProposalState::Claimed { .. } => true,
+            ProposalState::Voting | ProposalState::Withdrawn { .. } => {
+                current_height >= self.voting_end
+            }
+        }
+    }
+}
+
+/// Select the active proposals that have not been voted on, given the IDs of the proposals
+/// that a validator has already voted on.
+pub fn unvoted_proposals<'a>(
+    active: &'a [Proposal],
+    voted_ids: &BTreeSet<u64>,
+) -> Vec<&'a Proposal> {
+    active
+        .iter()
+        .filter(|proposal| !voted_ids.contains(&proposal.id))
+        .collect()
+}
+
+/// Validate a batch of proposals, reporting the result for every proposal rather than
+/// stopping at the first invalid one.
+///
+/// Each proposal is checked against the same limits as a proposal decoded from its proto
+/// representation. Parameter change proposals are additionally applied to the supplied app
+/// parameters, encoded as a [`serde_json::Value`] (see [`ParameterChange::apply_changes_raw`]),
+/// to check that their preconditions hold and that they target existing components.
+pub fn validate_proposals(
+    proposals: &[Proposal],
+    app_parameters: &serde_json::Value,
+) -> Vec<(u64, anyhow::Result<()>)> {
+    proposals
+        .iter()
+        .map(|proposal| {
+            let result = Proposal::try_from(pb::Proposal::from(proposal.clone())).and_then(|_| {
+                if let ProposalPayload::ParameterChange(change) = &proposal.payload {
+                    change.apply_changes_raw(app_parameters.clone())?;
+                }
+                Ok(())
+            });
+            (proposal.id, result)
+        })
+        .collect()
+}
+
 /// The machine-interpretable body of a proposal.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum ProposalPayload {
@@ -331,19 +762,58 @@ pub enum ProposalPayload {
         /// The identifier of the client to unfreeze.
         client_id: String,
     },
+    /// A proposal to pause or resume the scheduling of new auctions, e.g. during an incident.
+    /// Auctions that were already scheduled are unaffected.
+    PauseAuctions {
+        /// If `paused == true`, new auctions can't be scheduled until a later proposal resumes
+        /// them.
+        paused: bool,
+    },
+    /// An emergency upgrade proposal is immediately passed when enough validators approve it, like
+    /// an [`Emergency`](ProposalPayload::Emergency) proposal, and schedules an upgrade at the
+    /// specified height, like an [`UpgradePlan`](ProposalPayload::UpgradePlan) proposal.
+    EmergencyUpgrade {
+        /// The height at which the chain will halt for the upgrade, which should be shortly after
+        /// the proposal is submitted.
+        height: u64,
+    },
 }
 
 /// A TOML-serializable version of `ProposalPayload`, meant for human consumption.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ProposalPayloadToml {
-    Signaling { commit: Option<String> },
-    Emergency { halt_chain: bool },
+    Signaling {
+        commit: Option<String>,
+    },
+    Emergency {
+        halt_chain: bool,
+    },
     ParameterChange(ParameterChange),
-    CommunityPoolSpend { transaction: String },
-    UpgradePlan { height: u64 },
-    FreezeIbcClient { client_id: String },
-    UnfreezeIbcClient { client_id: String },
+    CommunityPoolSpend {
+        /// The transaction plan, encoded as base64 protobuf.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        transaction: Option<String>,
+        /// A path to a file containing the JSON-encoded transaction plan, as a reviewable
+        /// alternative to `transaction`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        transaction_file: Option<PathBuf>,
+    },
+    UpgradePlan {
+        height: u64,
+    },
+    FreezeIbcClient {
+        client_id: String,
+    },
+    UnfreezeIbcClient {
+        client_id: String,
+    },
+    PauseAuctions {
+        paused: bool,
+    },
+    EmergencyUpgrade {
+        height: u64,
+    },
 }
 
 impl TryFrom<ProposalPayloadToml> for ProposalPayload {
@@ -358,9 +828,12 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
             ProposalPayloadToml::ParameterChange(change) => {
                 ProposalPayload::ParameterChange(change)
             }
-            ProposalPayloadToml::CommunityPoolSpend { transaction } => {
-                ProposalPayload::CommunityPoolSpend {
-                    transaction_plan: Bytes::from(
+            ProposalPayloadToml::CommunityPoolSpend {
+                transaction,
+                transaction_file,
+            } => {
+                let transaction_plan = match (transaction, transaction_file) {
+                    (Some(transaction), None) => Bytes::from(
                         base64::Engine::decode(
                             &base64::engine::general_purpose::STANDARD,
                             transaction,
@@ -368,7 +841,21 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
                         .context("couldn't decode transaction plan from base64")?,
                     )
                     .to_vec(),
-                }
+                    (None, Some(path)) => {
+                        let json = std::fs::read_to_string(&path).with_context(|| {
+                            format!("couldn't read transaction plan from {}", path.display())
+                        })?;
+                        serde_json::from_str::<pb_transaction::TransactionPlan>(&json)
+                            .with_context(|| {
+                                format!("couldn't parse transaction plan in {}", path.display())
+                            })?
+                            .encode_to_vec()
+                    }
+                    _ => anyhow::bail!(
+                        "community pool spend proposal must specify exactly one of `transaction` or `transaction_file`"
+                    ),
+                };
+                ProposalPayload::CommunityPoolSpend { transaction_plan }
             }
             ProposalPayloadToml::UpgradePlan { height } => ProposalPayload::UpgradePlan { height },
             ProposalPayloadToml::FreezeIbcClient { client_id } => {
@@ -377,6 +864,12 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
             ProposalPayloadToml::UnfreezeIbcClient { client_id } => {
                 ProposalPayload::UnfreezeIbcClient { client_id }
             }
+            ProposalPayloadToml::PauseAuctions { paused } => {
+                ProposalPayload::PauseAuctions { paused }
+            }
+            ProposalPayloadToml::EmergencyUpgrade { height } => {
+                ProposalPayload::EmergencyUpgrade { height }
+            }
         })
     }
 }
@@ -393,10 +886,11 @@ impl From<ProposalPayload> for ProposalPayloadToml {
             }
             ProposalPayload::CommunityPoolSpend { transaction_plan } => {
                 ProposalPayloadToml::CommunityPoolSpend {
-                    transaction: base64::Engine::encode(
+                    transaction: Some(base64::Engine::encode(
                         &base64::engine::general_purpose::STANDARD,
                         transaction_plan,
-                    ),
+                    )),
+                    transaction_file: None,
                 }
             }
             ProposalPayload::UpgradePlan { height } => ProposalPayloadToml::UpgradePlan { height },
@@ -406,6 +900,12 @@ impl From<ProposalPayload> for ProposalPayloadToml {
             ProposalPayload::UnfreezeIbcClient { client_id } => {
                 ProposalPayloadToml::UnfreezeIbcClient { client_id }
             }
+            ProposalPayload::PauseAuctions { paused } => {
+                ProposalPayloadToml::PauseAuctions { paused }
+            }
+            ProposalPayload::EmergencyUpgrade { height } => {
+                ProposalPayloadToml::EmergencyUpgrade { height }
+            }
         }
     }
 }
@@ -419,11 +919,49 @@ impl ProposalPayload {
         matches!(self, ProposalPayload::Emergency { .. })
     }
 
+    pub fn is_emergency_upgrade(&self) -> bool {
+        matches!(self, ProposalPayload::EmergencyUpgrade { .. })
+    }
+
     pub fn is_ibc_freeze(&self) -> bool {
         matches!(self, ProposalPayload::FreezeIbcClient { .. })
             || matches!(self, ProposalPayload::UnfreezeIbcClient { .. })
     }
 
+    /// Returns whether the proposal affects IBC when enacted, i.e. whether it freezes or unfreezes
+    /// an IBC client, or changes the IBC parameters.
+    pub fn touches_ibc(&self) -> bool {
+        match self {
+            ProposalPayload::FreezeIbcClient { .. } | ProposalPayload::UnfreezeIbcClient { .. } => {
+                true
+            }
+            ProposalPayload::ParameterChange(change) => change
+                .changes
+                .iter()
+                .any(|parameter| parameter.component == "ibcParams"),
+            _ => false,
+        }
+    }
+
+    /// The names of the parameter groups modified by a parameter change, in the order of
+    /// [`APP_PARAMETER_COMPONENTS`], e.g. so that submission tools can warn about proposals that
+    /// change more than one component at a time.
+    ///
+    /// Other kinds of proposals don't change any parameters. Components that aren't part of the
+    /// app parameters are omitted, since such a change could never be enacted.
+    pub fn changed_components(&self) -> Vec<&'static str> {
+        match self {
+            ProposalPayload::ParameterChange(change) => {
+                let modified = change.modified_components();
+                APP_PARAMETER_COMPONENTS
+                    .into_iter()
+                    .filter(|component| modified.contains(component))
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
     pub fn is_parameter_change(&self) -> bool {
         matches!(self, ProposalPayload::ParameterChange { .. })
     }
@@ -431,4 +969,667 @@ impl ProposalPayload {
     pub fn is_community_pool_spend(&self) -> bool {
         matches!(self, ProposalPayload::CommunityPoolSpend { .. })
     }
+
+    /// Decode the transaction plan of a Community Pool spend, and list the recipient and value of
+    /// each of its Community Pool outputs, so that voters can see who would be paid.
+    ///
+    /// Other kinds of proposals don't pay anyone, and have no recipients.
+    pub fn community_pool_spend_recipients(&self) -> anyhow::Result<Vec<(Address, Value)>> {
+        use pb_transaction::action_plan::Action;
+
+        let ProposalPayload::CommunityPoolSpend { transaction_plan } = self else {
+            return Ok(Vec::new());
+        };
+        let plan = pb_transaction::TransactionPlan::decode(transaction_plan.as_slice())
+            .context("transaction plan was malformed")?;
+
+        plan.actions
+            .into_iter()
+            .filter_map(|action| match action.action {
+                Some(Action::CommunityPoolOutput(output)) => Some(output),
+                _ => None,
+            })
+            .map(|output| {
+                let address = output
+                    .address
+                    .ok_or_else(|| anyhow::anyhow!("community pool output is missing an address"))?
+                    .try_into()?;
+                let value = output
+                    .value
+                    .ok_or_else(|| anyhow::anyhow!("community pool output is missing a value"))?
+                    .try_into()?;
+                Ok((address, value))
+            })
+            .collect()
+    }
+
+    /// Returns whether this is a parameter change that would still apply on top of the
+    /// `current` app parameters, encoded as a [`serde_json::Value`].
+    ///
+    /// A parameter change stops being applicable when the parameters drift away from its
+    /// preconditions, e.g. because another parameter change was enacted in the meantime.
+    pub fn parameter_change_applicable(&self, current: &serde_json::Value) -> bool {
+        match self {
+            ProposalPayload::ParameterChange(change) => {
+                change.apply_changes_raw(current.clone()).is_ok()
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_PROPOSAL_KINDS: [ProposalKind; 9] = [
+        ProposalKind::Signaling,
+        ProposalKind::Emergency,
+        ProposalKind::ParameterChange,
+        ProposalKind::CommunityPoolSpend,
+        ProposalKind::UpgradePlan,
+        ProposalKind::FreezeIbcClient,
+        ProposalKind::UnfreezeIbcClient,
+        ProposalKind::PauseAuctions,
+        ProposalKind::EmergencyUpgrade,
+    ];
+
+    #[test]
+    fn proposal_kind_slug_roundtrip() {
+        for kind in ALL_PROPOSAL_KINDS {
+            let slug = kind.slug();
+            assert!(slug.chars().all(|c| c.is_ascii_lowercase() || c == '-'));
+            assert_eq!(ProposalKind::from_slug(slug).unwrap(), kind);
+        }
+        assert!(ProposalKind::from_slug("parameter_change").is_err());
+    }
+
+    #[test]
+    fn proposal_kind_from_str_covers_all_kinds() {
+        for kind in ALL_PROPOSAL_KINDS {
+            let name = kind.slug().replace('-', "_");
+            assert_eq!(ProposalKind::from_str(&name).unwrap(), kind);
+        }
+        assert!(ProposalKind::from_str("freeze-ibc-client").is_err());
+    }
+
+    fn proposal(payload: ProposalPayload) -> Proposal {
+        Proposal {
+            id: 1,
+            title: "Title".to_string(),
+            description: "Description.".to_string(),
+            payload,
+        }
+    }
+
+    #[test]
+    fn validate_signaling_commit() {
+        let signaling = |commit: Option<&str>| {
+            proposal(ProposalPayload::Signaling {
+                commit: commit.map(str::to_string),
+            })
+        };
+        assert!(signaling(None).validate(0).is_ok());
+        assert!(signaling(Some("a1b2c3d4")).validate(0).is_ok());
+        assert!(signaling(Some("main")).validate(0).is_err());
+        assert!(signaling(Some("")).validate(0).is_err());
+    }
+
+    #[test]
+    fn validate_emergency_limits() {
+        let mut emergency = proposal(ProposalPayload::Emergency { halt_chain: true });
+        assert!(emergency.validate(0).is_ok());
+        emergency.title = "x".repeat(81);
+        assert!(emergency.validate(0).is_err());
+    }
+
+    #[test]
+    fn validate_upgrade_height() {
+        let upgrade = proposal(ProposalPayload::UpgradePlan { height: 100 });
+        assert!(upgrade.validate(99).is_ok());
+        assert!(upgrade.validate(100).is_err());
+        assert!(upgrade.validate(200).is_err());
+    }
+
+    #[test]
+    fn validate_ibc_client_id() {
+        let freeze = |client_id: &str| {
+            proposal(ProposalPayload::FreezeIbcClient {
+                client_id: client_id.to_string(),
+            })
+        };
+        assert!(freeze("07-tendermint-0").validate(0).is_ok());
+        assert!(freeze("not a client").validate(0).is_err());
+        let unfreeze = proposal(ProposalPayload::UnfreezeIbcClient {
+            client_id: String::new(),
+        });
+        assert!(unfreeze.validate(0).is_err());
+    }
+
+    #[test]
+    fn validate_community_pool_spend_plan() {
+        use pb_transaction::action_plan::Action;
+
+        let spend = |actions: Vec<Action>| {
+            let plan = pb_transaction::TransactionPlan {
+                actions: actions
+                    .into_iter()
+                    .map(|action| pb_transaction::ActionPlan {
+                        action: Some(action),
+                    })
+                    .collect(),
+                ..Default::default()
+            };
+            proposal(ProposalPayload::CommunityPoolSpend {
+                transaction_plan: plan.encode_to_vec(),
+            })
+        };
+        assert!(spend(vec![]).validate(0).is_ok());
+        assert!(spend(vec![Action::CommunityPoolSpend(Default::default())])
+            .validate(0)
+            .is_ok());
+        let rejected = [
+            Action::Spend(Default::default()),
+            Action::Output(Default::default()),
+            Action::Swap(Default::default()),
+            Action::SwapClaim(Default::default()),
+            Action::DelegatorVote(Default::default()),
+            Action::UndelegateClaim(Default::default()),
+            Action::Delegate(Default::default()),
+            Action::Undelegate(Default::default()),
+            Action::ProposalSubmit(Default::default()),
+            Action::ProposalWithdraw(Default::default()),
+            Action::ProposalDepositClaim(Default::default()),
+        ];
+        for action in rejected {
+            assert!(
+                spend(vec![action.clone()]).validate(0).is_err(),
+                "{action:?} should be rejected"
+            );
+        }
+
+        let malformed = proposal(ProposalPayload::CommunityPoolSpend {
+            transaction_plan: vec![0xff; 4],
+        });
+        assert!(malformed.validate(0).is_err());
+    }
+
+    #[test]
+    fn community_pool_spend_toml_forms() {
+        let plan = pb_transaction::TransactionPlan {
+            actions: vec![pb_transaction::ActionPlan {
+                action: Some(pb_transaction::action_plan::Action::CommunityPoolSpend(
+                    Default::default(),
+                )),
+            }],
+            ..Default::default()
+        };
+        let spend = proposal(ProposalPayload::CommunityPoolSpend {
+            transaction_plan: plan.encode_to_vec(),
+        });
+
+        // Inline, as base64.
+        let inline = toml::to_string(&ProposalToml::from(spend.clone())).unwrap();
+        assert!(!inline.contains("transaction_file"));
+        let parsed: ProposalToml = toml::from_str(&inline).unwrap();
+        assert_eq!(Proposal::try_from(parsed).unwrap(), spend);
+
+        // By reference to a JSON file, next to the proposal file.
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("plan.json"),
+            serde_json::to_string(&plan).unwrap(),
+        )
+        .unwrap();
+        let by_file = r#"
+            id = 1
+            title = "Title"
+            description = "Description."
+            kind = "community_pool_spend"
+            transaction_file = "plan.json"
+        "#;
+        let parsed: ProposalToml = toml::from_str(by_file).unwrap();
+        assert_eq!(
+            Proposal::try_from(parsed.resolve_relative_to(dir.path())).unwrap(),
+            spend
+        );
+
+        // Exactly one of the two forms must be used.
+        let neither = r#"
+            id = 1
+            title = "Title"
+            description = "Description."
+            kind = "community_pool_spend"
+        "#;
+        let parsed: ProposalToml = toml::from_str(neither).unwrap();
+        assert!(Proposal::try_from(parsed).is_err());
+    }
+
+    #[test]
+    fn community_pool_spend_recipients_are_listed() {
+        use pb_transaction::action_plan::Action;
+        use penumbra_sdk_keys::test_keys;
+
+        let upenumbra = *penumbra_sdk_asset::STAKING_TOKEN_ASSET_ID;
+        let recipients = vec![
+            (
+                test_keys::ADDRESS_0.clone(),
+                Value {
+                    amount: 100u64.into(),
+                    asset_id: upenumbra,
+                },
+            ),
+            (
+                test_keys::ADDRESS_1.clone(),
+                Value {
+                    amount: 250u64.into(),
+                    asset_id: upenumbra,
+                },
+            ),
+        ];
+        let mut actions: Vec<_> = recipients
+            .iter()
+            .map(|(address, value)| pb_transaction::ActionPlan {
+                action: Some(Action::CommunityPoolOutput(pb::CommunityPoolOutput {
+                    value: Some((*value).into()),
+                    address: Some(address.clone().into()),
+                })),
+            })
+            .collect();
+        // Actions that don't pay anyone are skipped.
+        actions.insert(
+            1,
+            pb_transaction::ActionPlan {
+                action: Some(Action::CommunityPoolSpend(Default::default())),
+            },
+        );
+        let plan = pb_transaction::TransactionPlan {
+            actions,
+            ..Default::default()
+        };
+
+        let spend = ProposalPayload::CommunityPoolSpend {
+            transaction_plan: plan.encode_to_vec(),
+        };
+        assert_eq!(spend.community_pool_spend_recipients().unwrap(), recipients);
+        assert!(ProposalPayload::UpgradePlan { height: 100 }
+            .community_pool_spend_recipients()
+            .unwrap()
+            .is_empty());
+        assert!(ProposalPayload::CommunityPoolSpend {
+            transaction_plan: vec![0xff; 4]
+        }
+        .community_pool_spend_recipients()
+        .is_err());
+    }
+
+    #[test]
+    fn pause_auctions_roundtrip() {
+        for paused in [true, false] {
+            let pause = proposal(ProposalPayload::PauseAuctions { paused });
+            assert_eq!(pause.kind(), ProposalKind::PauseAuctions);
+
+            let proto = pb::Proposal::from(pause.clone());
+            assert_eq!(Proposal::try_from(proto).unwrap(), pause);
+
+            let toml = toml::to_string(&ProposalToml::from(pause.clone())).unwrap();
+            assert!(toml.contains("kind = \"pause_auctions\""));
+            let parsed: ProposalToml = toml::from_str(&toml).unwrap();
+            assert_eq!(Proposal::try_from(parsed).unwrap(), pause);
+        }
+        assert_eq!(
+            ProposalKind::try_from(pb::ProposalKind::from(ProposalKind::PauseAuctions)).unwrap(),
+            ProposalKind::PauseAuctions
+        );
+    }
+
+    #[test]
+    fn validate_pause_auctions() {
+        let mut pause = proposal(ProposalPayload::PauseAuctions { paused: true });
+        assert!(pause.validate(0).is_ok());
+        pause.description = "x".repeat(10_001);
+        assert!(pause.validate(0).is_err());
+    }
+
+    #[test]
+    fn emergency_upgrade_roundtrip() {
+        let upgrade = proposal(ProposalPayload::EmergencyUpgrade { height: 1_000 });
+        assert_eq!(upgrade.kind(), ProposalKind::EmergencyUpgrade);
+        assert!(upgrade.payload.is_emergency_upgrade());
+        assert!(!upgrade.payload.is_emergency());
+
+        let proto = pb::Proposal::from(upgrade.clone());
+        assert_eq!(Proposal::try_from(proto).unwrap(), upgrade);
+
+        let toml = toml::to_string(&ProposalToml::from(upgrade.clone())).unwrap();
+        assert!(toml.contains("kind = \"emergency_upgrade\""));
+        let parsed: ProposalToml = toml::from_str(&toml).unwrap();
+        assert_eq!(Proposal::try_from(parsed).unwrap(), upgrade);
+
+        assert!(upgrade.validate(999).is_ok());
+        assert!(upgrade.validate(1_000).is_err());
+    }
+
+    #[test]
+    fn parameter_change_components() {
+        use crate::change::EncodedParameter;
+
+        let parameter = |component: &str, key: &str, value: &str| EncodedParameter {
+            component: component.to_string(),
+            key: key.to_string(),
+            value: value.to_string(),
+        };
+        let change = |changes: Vec<EncodedParameter>| {
+            ProposalPayload::ParameterChange(ParameterChange {
+                changes,
+                preconditions: vec![parameter("sctParams", "epochDuration", "\"719\"")],
+            })
+        };
+
+        assert!(ProposalPayload::UpgradePlan { height: 100 }
+            .changed_components()
+            .is_empty());
+        // Setting a parameter to the value of its precondition doesn't change it.
+        assert!(
+            change(vec![parameter("sctParams", "epochDuration", "\"719\"")])
+                .changed_components()
+                .is_empty()
+        );
+        assert_eq!(
+            change(vec![parameter("sctParams", "epochDuration", "\"800\"")]).changed_components(),
+            vec!["sctParams"]
+        );
+        assert_eq!(
+            change(vec![
+                parameter("stakeParams", "activeValidatorLimit", "\"100\""),
+                parameter("sctParams", "epochDuration", "\"800\""),
+                parameter("stakeParams", "minValidatorStake", "\"1\""),
+                parameter("notParams", "key", "\"1\""),
+            ])
+            .changed_components(),
+            vec!["sctParams", "stakeParams"]
+        );
+    }
+
+    #[test]
+    fn proposals_touching_ibc() {
+        let client_id = "07-tendermint-0".to_string();
+        assert!(ProposalPayload::FreezeIbcClient {
+            client_id: client_id.clone()
+        }
+        .touches_ibc());
+        assert!(ProposalPayload::UnfreezeIbcClient { client_id }.touches_ibc());
+        assert!(!ProposalPayload::Emergency { halt_chain: true }.touches_ibc());
+
+        let change = |component: &str| {
+            ProposalPayload::ParameterChange(ParameterChange {
+                changes: vec![crate::change::EncodedParameter {
+                    component: component.to_string(),
+                    key: "ibcEnabled".to_string(),
+                    value: "false".to_string(),
+                }],
+                preconditions: vec![],
+            })
+        };
+        assert!(change("ibcParams").touches_ibc());
+        assert!(!change("governanceParams").touches_ibc());
+    }
+
+    #[test]
+    fn proposal_conclusion() {
+        let status = |payload, state| ProposalStatus {
+            proposal: proposal(payload),
+            state,
+            voting_end: 100,
+        };
+        let signaling = || ProposalPayload::Signaling { commit: None };
+
+        let active = status(signaling(), ProposalState::Voting);
+        assert!(!active.is_concluded(99));
+        assert!(active.is_concluded(100));
+
+        let withdrawn = status(
+            signaling(),
+            ProposalState::Withdrawn {
+                reason: "superseded".to_string(),
+            },
+        );
+        assert!(!withdrawn.is_concluded(50));
+
+        let emergency = status(
+            ProposalPayload::Emergency { halt_chain: false },
+            ProposalState::Finished {
+                outcome: crate::proposal_state::Outcome::Passed,
+            },
+        );
+        assert!(emergency.is_concluded(50));
+    }
+
+    #[test]
+    fn proposal_summaries() {
+        use crate::change::EncodedParameter;
+
+        let summary = |payload: ProposalPayload| proposal(payload).summary();
+        let parameter = |component: &str, key: &str, value: &str| EncodedParameter {
+            component: component.to_string(),
+            key: key.to_string(),
+            value: value.to_string(),
+        };
+
+        assert_eq!(
+            summary(ProposalPayload::Signaling {
+                commit: Some("abc123".to_string())
+            }),
+            "Signaling: commit abc123"
+        );
+        assert_eq!(
+            summary(ProposalPayload::Emergency { halt_chain: true }),
+            "Emergency: halt the chain"
+        );
+
+        // Only the parameters that differ from their preconditions count as modified.
+        let one_group = ParameterChange {
+            changes: vec![
+                parameter("sctParams", "epochDuration", "\"800\""),
+                parameter("ibcParams", "ibcEnabled", "true"),
+            ],
+            preconditions: vec![
+                parameter("sctParams", "epochDuration", "\"719\""),
+                parameter("ibcParams", "ibcEnabled", "true"),
+            ],
+        };
+        assert_eq!(
+            summary(ProposalPayload::ParameterChange(one_group)),
+            "Parameter change: 1 parameter group modified"
+        );
+        let three_groups = ParameterChange {
+            changes: vec![
+                parameter("sctParams", "epochDuration", "\"800\""),
+                parameter("ibcParams", "ibcEnabled", "false"),
+                parameter("ibcParams", "inboundIcs20TransfersEnabled", "false"),
+                parameter("stakeParams", "activeValidatorLimit", "\"100\""),
+            ],
+            preconditions: vec![],
+        };
+        assert_eq!(
+            summary(ProposalPayload::ParameterChange(three_groups)),
+            "Parameter change: 3 parameter groups modified"
+        );
+
+        let plan = pb_transaction::TransactionPlan {
+            actions: vec![Default::default(), Default::default()],
+            ..Default::default()
+        };
+        assert_eq!(
+            summary(ProposalPayload::CommunityPoolSpend {
+                transaction_plan: plan.encode_to_vec()
+            }),
+            "Community Pool spend: 2 actions"
+        );
+        assert_eq!(
+            summary(ProposalPayload::UpgradePlan { height: 1234567 }),
+            "Upgrade at height 1234567"
+        );
+        assert_eq!(
+            summary(ProposalPayload::FreezeIbcClient {
+                client_id: "07-tendermint-0".to_string()
+            }),
+            "Freeze IBC client 07-tendermint-0"
+        );
+        assert_eq!(
+            summary(ProposalPayload::UnfreezeIbcClient {
+                client_id: "07-tendermint-0".to_string()
+            }),
+            "Unfreeze IBC client 07-tendermint-0"
+        );
+        assert_eq!(
+            summary(ProposalPayload::PauseAuctions { paused: false }),
+            "Resume auctions"
+        );
+        assert_eq!(
+            summary(ProposalPayload::EmergencyUpgrade { height: 100 }),
+            "Emergency upgrade at height 100"
+        );
+    }
+
+    #[test]
+    fn split_and_join_roundtrip() {
+        let original = proposal(ProposalPayload::UpgradePlan { height: 100 });
+        let (metadata, payload) = original.split();
+        assert_eq!(metadata.id, 1);
+        assert_eq!(metadata.title, "Title");
+        assert_eq!(metadata.description, "Description.");
+        assert_eq!(payload, ProposalPayload::UpgradePlan { height: 100 });
+        assert_eq!(Proposal::join(metadata, payload), original);
+    }
+
+    #[test]
+    fn emergency_halt_proposal() {
+        let proposal = Proposal::emergency_halt(3, "Halt", "Halt the chain.").unwrap();
+
+        assert_eq!(proposal.id, 3);
+        assert_eq!(proposal.kind(), ProposalKind::Emergency);
+        assert_eq!(
+            proposal.payload,
+            ProposalPayload::Emergency { halt_chain: true }
+        );
+
+        // The title length limit is enforced.
+        assert!(Proposal::emergency_halt(3, "x".repeat(81), "Halt the chain.").is_err());
+    }
+
+    #[test]
+    fn parameter_change_to_markdown() {
+        let proposal = Proposal {
+            id: 7,
+            title: "Longer votes".to_string(),
+            description: "Extend the voting period.".to_string(),
+            payload: ProposalPayload::ParameterChange(ParameterChange {
+                changes: vec![crate::change::EncodedParameter {
+                    component: "governanceParams".to_string(),
+                    key: "proposalVotingBlocks".to_string(),
+                    value: r#""17281""#.to_string(),
+                }],
+                preconditions: vec![],
+            }),
+        };
+
+        assert_eq!(
+            proposal.to_markdown(),
+            "# Proposal #7: Longer votes\n\n\
+             Extend the voting period.\n\n\
+             ## Details\n\n\
+             - **Kind:** parameter change\n\n\
+             ### Changes\n\n\
+             - `governanceParams.proposalVotingBlocks` = `\"17281\"`\n"
+        );
+    }
+
+    #[test]
+    fn proposals_without_votes() {
+        let active = vec![
+            Proposal::emergency_halt(1, "One", "").unwrap(),
+            Proposal::emergency_halt(2, "Two", "").unwrap(),
+            Proposal::emergency_halt(3, "Three", "").unwrap(),
+        ];
+        let voted_ids = BTreeSet::from([2, 4]);
+
+        let unvoted: Vec<u64> = unvoted_proposals(&active, &voted_ids)
+            .into_iter()
+            .map(|proposal| proposal.id)
+            .collect();
+        assert_eq!(unvoted, vec![1, 3]);
+    }
+
+    #[test]
+    fn parameter_change_applicability() {
+        let payload = ProposalPayload::ParameterChange(ParameterChange {
+            changes: vec![crate::change::EncodedParameter {
+                component: "governanceParams".to_string(),
+                key: "proposalVotingBlocks".to_string(),
+                value: r#""17281""#.to_string(),
+            }],
+            preconditions: vec![crate::change::EncodedParameter {
+                component: "governanceParams".to_string(),
+                key: "proposalVotingBlocks".to_string(),
+                value: r#""17280""#.to_string(),
+            }],
+        });
+
+        let current = serde_json::json!({
+            "governanceParams": { "proposalVotingBlocks": "17280" }
+        });
+        assert!(payload.parameter_change_applicable(&current));
+
+        let drifted = serde_json::json!({
+            "governanceParams": { "proposalVotingBlocks": "20000" }
+        });
+        assert!(!payload.parameter_change_applicable(&drifted));
+
+        let signaling = ProposalPayload::Signaling { commit: None };
+        assert!(!signaling.parameter_change_applicable(&current));
+    }
+
+    #[test]
+    fn batch_validation_reports_every_proposal() {
+        let app_parameters = serde_json::json!({
+            "governanceParams": { "proposalVotingBlocks": "17280" }
+        });
+        let parameter_change = |id, component: &str| Proposal {
+            id,
+            title: "Change".to_string(),
+            description: String::new(),
+            payload: ProposalPayload::ParameterChange(ParameterChange {
+                changes: vec![crate::change::EncodedParameter {
+                    component: component.to_string(),
+                    key: "proposalVotingBlocks".to_string(),
+                    value: r#""17281""#.to_string(),
+                }],
+                preconditions: vec![],
+            }),
+        };
+
+        let proposals = vec![
+            Proposal::emergency_halt(1, "Halt", "Halt the chain.").unwrap(),
+            Proposal {
+                id: 2,
+                title: "x".repeat(81),
+                description: String::new(),
+                payload: ProposalPayload::Signaling { commit: None },
+            },
+            parameter_change(3, "governanceParams"),
+            parameter_change(4, "unknownParams"),
+        ];
+
+        let results = validate_proposals(&proposals, &app_parameters);
+
+        assert_eq!(
+            results.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.is_err());
+        assert!(results[2].1.is_ok());
+        assert!(results[3].1.is_err());
+    }
 }
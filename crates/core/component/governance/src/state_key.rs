@@ -128,6 +128,10 @@ pub fn proposal_started() -> &'static str {
     "governance/proposal_started"
 }
 
+pub fn auctions_paused() -> &'static str {
+    "governance/auctions_paused"
+}
+
 pub mod upgrades {
     pub fn next_upgrade() -> &'static str {
         "governance/upgrades/next_upgrade"
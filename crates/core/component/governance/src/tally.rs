@@ -6,6 +6,7 @@ use std::{
     str::FromStr,
 };
 
+use penumbra_sdk_num::Amount;
 use penumbra_sdk_proto::{penumbra::core::component::governance::v1 as pb, DomainType};
 
 use crate::{
@@ -191,6 +192,89 @@ impl Tally {
     }
 }
 
+/// Compute the fraction of the total voting power that participated in a vote, for display purposes.
+///
+/// The participating voting power and the total voting power are kept as an exact ratio, and only
+/// rendered as a float at the end. A zero total voting power has a participation rate of zero.
+pub fn participation_rate(yes: u64, no: u64, abstain: u64, total_voting_power: u64) -> f64 {
+    let participated = yes.saturating_add(no).saturating_add(abstain);
+    Ratio::new(participated, total_voting_power).as_f64()
+}
+
+/// Compute the minimum participating voting power for a vote to meet quorum, given the total
+/// staked voting power.
+///
+/// This is the smallest amount whose ratio to `total_staked` is at least the
+/// `proposal_valid_quorum`, computed exactly rather than through floating point. A quorum with a
+/// zero denominator can't be expressed as a fraction of the stake, and requires the entire stake.
+pub fn quorum_threshold(total_staked: Amount, params: &GovernanceParameters) -> Amount {
+    let Ratio {
+        numerator,
+        denominator,
+    } = params.proposal_valid_quorum;
+    if denominator == 0 {
+        return total_staked;
+    }
+
+    // Split the stake into whole multiples of the denominator and a remainder, so that the
+    // multiplication by the numerator can't overflow for any realistic stake.
+    let (numerator, denominator) = (u128::from(numerator), u128::from(denominator));
+    let total_staked = total_staked.value();
+    let (quotient, remainder) = (total_staked / denominator, total_staked % denominator);
+
+    quotient
+        .saturating_mul(numerator)
+        .saturating_add((remainder * numerator).div_ceil(denominator))
+        .into()
+}
+
+/// Compute the additional yes voting power needed for a proposal to pass, given its current tally
+/// and the total voting power, e.g. for dashboards showing "needs X more yes votes".
+///
+/// The outcome is evaluated exactly as the chain does at the end of the voting period, see
+/// [`Tally::outcome`]. A proposal that is already passing needs no more votes. If no amount of
+/// additional yes votes would make the proposal pass, the result is the largest amount that could
+/// be added to the tally.
+pub fn votes_needed_to_pass(
+    current_yes: u64,
+    current_no: u64,
+    current_abstain: u64,
+    total_power: u64,
+    params: &GovernanceParameters,
+) -> Amount {
+    let passes = |additional_yes: u64| {
+        Tally {
+            yes: current_yes.saturating_add(additional_yes),
+            no: current_no,
+            abstain: current_abstain,
+        }
+        .outcome(total_power, params)
+        .is_pass()
+    };
+    if passes(0) {
+        return Amount::zero();
+    }
+
+    // Passing is monotonic in the yes votes: more yes votes only help to meet quorum, to avoid
+    // slashing, and to clear the pass threshold. So binary search for the least passing amount.
+    let cast = current_yes
+        .saturating_add(current_no)
+        .saturating_add(current_abstain);
+    let (mut failing, mut passing) = (0u64, u64::MAX - cast);
+    if !passes(passing) {
+        return passing.into();
+    }
+    while passing - failing > 1 {
+        let middle = failing + (passing - failing) / 2;
+        if passes(middle) {
+            passing = middle;
+        } else {
+            failing = middle;
+        }
+    }
+    passing.into()
+}
+
 /// This is a ratio of two `u64` values, intended to be used solely in governance parameters and
 /// tallying. It only implements construction and comparison, not arithmetic, to reduce the trusted
 /// codebase for governance.
@@ -237,6 +321,15 @@ impl Ratio {
             denominator,
         }
     }
+
+    /// Render the ratio as a float, for display purposes only. A ratio with a zero denominator is
+    /// rendered as zero.
+    fn as_f64(&self) -> f64 {
+        if self.denominator == 0 {
+            return 0.0;
+        }
+        self.numerator as f64 / self.denominator as f64
+    }
 }
 
 impl PartialEq for Ratio {
@@ -280,3 +373,67 @@ impl From<pb::Ratio> for Ratio {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn participation_rate_is_fraction_of_total() {
+        assert_eq!(participation_rate(0, 0, 0, 100), 0.0);
+        assert_eq!(participation_rate(20, 20, 10, 100), 0.5);
+        assert_eq!(participation_rate(60, 30, 10, 100), 1.0);
+        assert_eq!(participation_rate(0, 0, 0, 0), 0.0);
+    }
+
+    #[test]
+    fn votes_needed_to_pass_from_tally() {
+        // The default parameters require 40% of the stake to participate.
+        let params = GovernanceParameters::default();
+
+        // Already passing.
+        assert_eq!(
+            votes_needed_to_pass(500, 100, 0, 1_000, &params),
+            Amount::zero()
+        );
+        // Short of quorum: 400 votes are needed, out of which 150 were cast.
+        assert_eq!(
+            votes_needed_to_pass(100, 0, 50, 1_000, &params),
+            250u64.into()
+        );
+        // No votes cast at all.
+        assert_eq!(votes_needed_to_pass(0, 0, 0, 1_000, &params), 400u64.into());
+
+        let needed = votes_needed_to_pass(100, 0, 50, 1_000, &params).value() as u64;
+        let tally = Tally {
+            yes: 100 + needed,
+            no: 0,
+            abstain: 50,
+        };
+        assert!(tally.outcome(1_000, &params).is_pass());
+    }
+
+    #[test]
+    fn quorum_threshold_is_exact() {
+        // The default parameters require 40% of the stake to participate.
+        let params = GovernanceParameters::default();
+        assert_eq!(quorum_threshold(1_000u64.into(), &params), 400u64.into());
+        // 40% of 1001 is 400.4, which rounds up to meet quorum.
+        assert_eq!(quorum_threshold(1_001u64.into(), &params), 401u64.into());
+        assert_eq!(quorum_threshold(0u64.into(), &params), 0u64.into());
+
+        let threshold = quorum_threshold(1_001u64.into(), &params);
+        let tally = Tally {
+            yes: threshold.value() as u64,
+            no: 0,
+            abstain: 0,
+        };
+        assert!(tally.meets_quorum(1_001, &params));
+        let tally = Tally {
+            yes: threshold.value() as u64 - 1,
+            no: 0,
+            abstain: 0,
+        };
+        assert!(!tally.meets_quorum(1_001, &params));
+    }
+}
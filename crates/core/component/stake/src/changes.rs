@@ -1,7 +1,9 @@
-use crate::{Delegate, Undelegate};
+use crate::{Delegate, IdentityKey, Undelegate};
 use anyhow::Result;
+use penumbra_sdk_num::Amount;
 use penumbra_sdk_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Data structure used to track queued delegation changes that have been
 /// committed to the chain but not yet processed at the epoch boundary.
@@ -12,6 +14,153 @@ pub struct DelegationChanges {
     pub undelegations: Vec<Undelegate>,
 }
 
+/// A single delegation change, tagged with the epoch it was processed in, for event-driven
+/// consumers such as indexers.
+///
+/// Amounts are in units of unbonded stake.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DelegationEvent {
+    Delegated {
+        validator: IdentityKey,
+        amount: Amount,
+        epoch: u64,
+    },
+    Undelegated {
+        validator: IdentityKey,
+        amount: Amount,
+        epoch: u64,
+    },
+}
+
+/// Delegation changes netted into a single signed delta per validator, see
+/// [`DelegationChanges::compact`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompactedDelegationChanges {
+    /// The net change in the supply of each validator's delegation token, counting
+    /// delegations as positive and undelegations as negative.
+    pub deltas: BTreeMap<IdentityKey, i128>,
+}
+
+impl CompactedDelegationChanges {
+    /// The number of validators with pending changes.
+    pub fn len(&self) -> usize {
+        self.deltas.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.deltas.is_empty()
+    }
+}
+
+impl DelegationChanges {
+    /// The total amount undelegated from each validator, in units of unbonded stake.
+    pub fn as_undelegation_deltas(&self) -> BTreeMap<IdentityKey, Amount> {
+        let mut totals: BTreeMap<IdentityKey, Amount> = BTreeMap::new();
+        for undelegation in &self.undelegations {
+            let total = totals.entry(undelegation.validator_identity).or_default();
+            *total = total.saturating_add(&undelegation.unbonded_amount);
+        }
+        totals
+    }
+
+    /// The net change in the supply of each validator's delegation token, counting
+    /// delegations as positive and undelegations as negative.
+    ///
+    /// Validators whose delegations and undelegations cancel out are omitted.
+    pub fn net_change(&self) -> BTreeMap<IdentityKey, i128> {
+        let mut net = self.compact().deltas;
+        net.retain(|_, change| *change != 0);
+        net
+    }
+
+    /// Net the delegations and undelegations of each validator into a single signed delta,
+    /// so that applying the changes at the epoch boundary takes one entry per validator.
+    ///
+    /// Unlike [`net_change`](Self::net_change), validators whose changes cancel out are kept,
+    /// with a zero delta.
+    pub fn compact(&self) -> CompactedDelegationChanges {
+        let signed = |amount: Amount| i128::try_from(amount.value()).unwrap_or(i128::MAX);
+
+        let mut deltas: BTreeMap<IdentityKey, i128> = BTreeMap::new();
+        for delegation in &self.delegations {
+            let change = deltas.entry(delegation.validator_identity).or_default();
+            *change = change.saturating_add(signed(delegation.delegation_amount));
+        }
+        for undelegation in &self.undelegations {
+            let change = deltas.entry(undelegation.validator_identity).or_default();
+            *change = change.saturating_sub(signed(undelegation.delegation_amount));
+        }
+        CompactedDelegationChanges { deltas }
+    }
+
+    /// Convert the changes into one event per delegation and undelegation, in that order, all
+    /// tagged with the supplied `epoch`.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn into_events(&self, epoch: u64) -> Vec<DelegationEvent> {
+        let delegated = self
+            .delegations
+            .iter()
+            .map(|delegation| DelegationEvent::Delegated {
+                validator: delegation.validator_identity,
+                amount: delegation.unbonded_amount,
+                epoch,
+            });
+        let undelegated =
+            self.undelegations
+                .iter()
+                .map(|undelegation| DelegationEvent::Undelegated {
+                    validator: undelegation.validator_identity,
+                    amount: undelegation.unbonded_amount,
+                    epoch,
+                });
+        delegated.chain(undelegated).collect()
+    }
+
+    /// Report the delegated and undelegated amounts for each validator, in units of
+    /// unbonded stake, as gauges on the supplied metrics recorder.
+    #[cfg(feature = "component")]
+    pub fn to_metrics(&self, recorder: &dyn metrics::Recorder) {
+        use crate::component::metrics::{
+            DELEGATION_CHANGES_DELEGATED, DELEGATION_CHANGES_UNDELEGATED,
+        };
+
+        let mut totals: BTreeMap<IdentityKey, (Amount, Amount)> = BTreeMap::new();
+        for delegation in &self.delegations {
+            let (delegated, _) = totals.entry(delegation.validator_identity).or_default();
+            *delegated = delegated.saturating_add(&delegation.unbonded_amount);
+        }
+        for undelegation in &self.undelegations {
+            let (_, undelegated) = totals.entry(undelegation.validator_identity).or_default();
+            *undelegated = undelegated.saturating_add(&undelegation.unbonded_amount);
+        }
+
+        metrics::with_local_recorder(recorder, || {
+            for (identity_key, (delegated, undelegated)) in totals {
+                metrics::gauge!(DELEGATION_CHANGES_DELEGATED, "identity_key" => identity_key.to_string())
+                    .set(delegated.value() as f64);
+                metrics::gauge!(DELEGATION_CHANGES_UNDELEGATED, "identity_key" => identity_key.to_string())
+                    .set(undelegated.value() as f64);
+            }
+        });
+    }
+}
+
+impl std::ops::AddAssign for DelegationChanges {
+    fn add_assign(&mut self, other: DelegationChanges) {
+        self.delegations.extend(other.delegations);
+        self.undelegations.extend(other.undelegations);
+    }
+}
+
+impl std::ops::Add for DelegationChanges {
+    type Output = DelegationChanges;
+
+    fn add(mut self, other: DelegationChanges) -> DelegationChanges {
+        self += other;
+        self
+    }
+}
+
 impl DomainType for DelegationChanges {
     type Proto = pb::DelegationChanges;
 }
@@ -42,3 +191,195 @@ impl TryFrom<pb::DelegationChanges> for DelegationChanges {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use decaf377_rdsa::VerificationKeyBytes;
+    #[cfg(feature = "component")]
+    use metrics_util::debugging::DebuggingRecorder;
+    use penumbra_sdk_sct::epoch::Epoch;
+
+    fn undelegate(validator_identity: IdentityKey, amount: u64) -> Undelegate {
+        Undelegate {
+            validator_identity,
+            from_epoch: Epoch {
+                index: 1,
+                start_height: 0,
+            },
+            unbonded_amount: amount.into(),
+            delegation_amount: amount.into(),
+        }
+    }
+
+    fn delegate(validator_identity: IdentityKey, amount: u64) -> Delegate {
+        Delegate {
+            validator_identity,
+            epoch_index: 1,
+            unbonded_amount: amount.into(),
+            delegation_amount: amount.into(),
+        }
+    }
+
+    #[test]
+    fn net_change_per_validator() {
+        let first = IdentityKey(VerificationKeyBytes::from([1u8; 32]));
+        let second = IdentityKey(VerificationKeyBytes::from([2u8; 32]));
+
+        assert!(DelegationChanges::default().net_change().is_empty());
+
+        let cancelling = DelegationChanges {
+            delegations: vec![delegate(first, 50)],
+            undelegations: vec![undelegate(first, 50)],
+        };
+        assert!(cancelling.net_change().is_empty());
+
+        let changes = DelegationChanges {
+            delegations: vec![delegate(first, 100), delegate(second, 10)],
+            undelegations: vec![undelegate(first, 30), undelegate(second, 25)],
+        };
+        assert_eq!(
+            changes.net_change(),
+            BTreeMap::from([(first, 70), (second, -15)])
+        );
+    }
+
+    #[test]
+    fn changes_compact_to_one_delta_per_validator() {
+        let first = IdentityKey(VerificationKeyBytes::from([1u8; 32]));
+        let second = IdentityKey(VerificationKeyBytes::from([2u8; 32]));
+        let changes = DelegationChanges {
+            delegations: vec![
+                delegate(first, 100),
+                delegate(second, 10),
+                delegate(first, 20),
+                delegate(second, 5),
+                delegate(first, 1),
+                delegate(second, 15),
+            ],
+            undelegations: vec![
+                undelegate(first, 30),
+                undelegate(second, 30),
+                undelegate(first, 6),
+                undelegate(second, 3),
+            ],
+        };
+
+        let compacted = changes.compact();
+        assert_eq!(compacted.len(), 2);
+        assert_eq!(
+            compacted.deltas,
+            BTreeMap::from([(first, 85), (second, -3)])
+        );
+
+        let cancelling = DelegationChanges {
+            delegations: vec![delegate(first, 50)],
+            undelegations: vec![undelegate(first, 50)],
+        };
+        assert_eq!(cancelling.compact().deltas, BTreeMap::from([(first, 0)]));
+        assert!(DelegationChanges::default().compact().is_empty());
+    }
+
+    #[test]
+    fn changes_convert_to_events() {
+        let first = IdentityKey(VerificationKeyBytes::from([1u8; 32]));
+        let second = IdentityKey(VerificationKeyBytes::from([2u8; 32]));
+        let changes = DelegationChanges {
+            delegations: vec![delegate(first, 100), delegate(second, 10)],
+            undelegations: vec![undelegate(first, 30)],
+        };
+
+        assert_eq!(
+            changes.into_events(7),
+            vec![
+                DelegationEvent::Delegated {
+                    validator: first,
+                    amount: 100u64.into(),
+                    epoch: 7,
+                },
+                DelegationEvent::Delegated {
+                    validator: second,
+                    amount: 10u64.into(),
+                    epoch: 7,
+                },
+                DelegationEvent::Undelegated {
+                    validator: first,
+                    amount: 30u64.into(),
+                    epoch: 7,
+                },
+            ]
+        );
+        assert!(DelegationChanges::default().into_events(7).is_empty());
+    }
+
+    #[test]
+    fn changes_are_concatenated_across_blocks() {
+        let validator = IdentityKey(VerificationKeyBytes::from([1u8; 32]));
+        let block = |delegated: u64, undelegated: u64| DelegationChanges {
+            delegations: vec![delegate(validator, delegated)],
+            undelegations: vec![undelegate(validator, undelegated)],
+        };
+
+        let mut epoch_total = block(10, 1) + block(20, 2);
+        epoch_total += block(30, 3);
+
+        let delegated: Vec<Amount> = epoch_total
+            .delegations
+            .iter()
+            .map(|delegation| delegation.delegation_amount)
+            .collect();
+        let undelegated: Vec<Amount> = epoch_total
+            .undelegations
+            .iter()
+            .map(|undelegation| undelegation.delegation_amount)
+            .collect();
+        assert_eq!(delegated, [10u64, 20, 30].map(Amount::from));
+        assert_eq!(undelegated, [1u64, 2, 3].map(Amount::from));
+    }
+
+    #[test]
+    fn undelegations_are_summed_per_validator() {
+        let first = IdentityKey(VerificationKeyBytes::from([1u8; 32]));
+        let second = IdentityKey(VerificationKeyBytes::from([2u8; 32]));
+        let changes = DelegationChanges {
+            delegations: vec![delegate(second, 100)],
+            undelegations: vec![
+                undelegate(first, 40),
+                undelegate(second, 10),
+                undelegate(first, 25),
+            ],
+        };
+
+        let deltas = changes.as_undelegation_deltas();
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas.get(&first), Some(&Amount::from(65u64)));
+        assert_eq!(deltas.get(&second), Some(&Amount::from(10u64)));
+    }
+
+    #[cfg(feature = "component")]
+    #[test]
+    fn metrics_are_reported_per_validator() {
+        use crate::component::metrics::{
+            DELEGATION_CHANGES_DELEGATED, DELEGATION_CHANGES_UNDELEGATED,
+        };
+
+        let validator = IdentityKey(VerificationKeyBytes::from([1u8; 32]));
+        let changes = DelegationChanges {
+            delegations: vec![delegate(validator, 100)],
+            undelegations: vec![undelegate(validator, 40)],
+        };
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        changes.to_metrics(&recorder);
+
+        let names: Vec<String> = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .map(|(key, _, _, _)| key.key().name().to_string())
+            .collect();
+        assert!(names.contains(&DELEGATION_CHANGES_DELEGATED.to_string()));
+        assert!(names.contains(&DELEGATION_CHANGES_UNDELEGATED.to_string()));
+    }
+}
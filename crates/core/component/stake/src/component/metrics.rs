@@ -20,6 +20,18 @@ pub fn register_metrics() {
         Unit::Count,
         "The number of missed blocks per validator"
     );
+    describe_gauge!(
+        DELEGATION_CHANGES_DELEGATED,
+        Unit::Count,
+        "The amount of stake delegated per validator in the pending delegation changes"
+    );
+    describe_gauge!(
+        DELEGATION_CHANGES_UNDELEGATED,
+        Unit::Count,
+        "The amount of stake undelegated per validator in the pending delegation changes"
+    );
 }
 
 pub const MISSED_BLOCKS: &str = "penumbra_stake_missed_blocks";
+pub const DELEGATION_CHANGES_DELEGATED: &str = "penumbra_stake_delegation_changes_delegated";
+pub const DELEGATION_CHANGES_UNDELEGATED: &str = "penumbra_stake_delegation_changes_undelegated";
@@ -44,7 +44,7 @@ pub use self::identity_key::IdentityKey;
 pub use self::penalty::Penalty;
 pub use self::unbonding_token::UnbondingToken;
 
-pub use self::changes::DelegationChanges;
+pub use self::changes::{CompactedDelegationChanges, DelegationChanges, DelegationEvent};
 pub use self::current_consensus_keys::CurrentConsensusKeys;
 pub use self::funding_stream::{FundingStream, FundingStreams};
 pub use self::uptime::Uptime;
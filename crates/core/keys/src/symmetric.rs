@@ -1,7 +1,7 @@
 use crate::keys::{IncomingViewingKey, OutgoingViewingKey};
 use anyhow::{anyhow, Result};
 use chacha20poly1305::{
-    aead::{Aead, NewAead},
+    aead::{Aead, AeadInPlace, NewAead},
     ChaCha20Poly1305, Key, Nonce,
 };
 use decaf377_ka as ka;
@@ -128,6 +128,21 @@ impl PayloadKey {
             .decrypt(nonce, ciphertext.as_ref())
             .map_err(|_| anyhow::anyhow!("decryption error"))
     }
+
+    /// Decrypt a swap in place using the `PayloadKey`, replacing the ciphertext in `buffer`
+    /// with the plaintext.
+    ///
+    /// This lets callers decrypting many swaps reuse a single buffer.
+    pub fn decrypt_swap_in_place(&self, buffer: &mut Vec<u8>) -> Result<()> {
+        let cipher = ChaCha20Poly1305::new(&self.0);
+
+        let nonce_bytes = PayloadKind::Swap.nonce();
+        let nonce = Nonce::from_slice(&nonce_bytes);
+
+        cipher
+            .decrypt_in_place(nonce, b"", buffer)
+            .map_err(|_| anyhow::anyhow!("decryption error"))
+    }
 }
 
 impl TryFrom<&[u8]> for PayloadKey {
@@ -3,6 +3,8 @@
 
 use anyhow::Result;
 use decaf377_fmd::Precision;
+use penumbra_sdk_asset::{asset, Balance, Value};
+use penumbra_sdk_auction::auction::AuctionNft;
 use penumbra_sdk_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
 use penumbra_sdk_dex::{
     lp::action::{PositionClose, PositionOpen},
@@ -15,12 +17,13 @@ use penumbra_sdk_governance::{
 };
 use penumbra_sdk_ibc::IbcRelay;
 use penumbra_sdk_keys::{Address, FullViewingKey, PayloadKey};
-use penumbra_sdk_proto::{core::transaction::v1 as pb, DomainType};
+use penumbra_sdk_proto::{core::transaction::v1 as pb, DomainType, Message as _};
 use penumbra_sdk_shielded_pool::{Ics20Withdrawal, OutputPlan, SpendPlan};
 use penumbra_sdk_stake::{Delegate, Undelegate, UndelegateClaimPlan};
 use penumbra_sdk_txhash::{EffectHash, EffectingData};
 use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 mod action;
 mod auth;
@@ -356,6 +359,14 @@ impl TransactionPlan {
             .sum()
     }
 
+    /// The length of the proto encoding of this plan.
+    ///
+    /// This is the size of the plan itself, not of the transaction built from it, which
+    /// carries proofs, signatures and encrypted payloads in place of the plaintext plans.
+    pub fn encoded_len(&self) -> usize {
+        pb::TransactionPlan::from(self.clone()).encoded_len()
+    }
+
     /// Method to populate the detection data for this transaction plan.
     pub fn populate_detection_data<R: CryptoRng + Rng>(
         &mut self,
@@ -400,6 +411,61 @@ impl TransactionPlan {
     }
 }
 
+/// Check that the auction actions of a transaction plan are balanced by the rest of the plan.
+///
+/// Scheduling an auction consumes its input and mints an opened auction NFT, and withdrawing
+/// an auction burns the previous auction NFT and mints its reserves along with the next
+/// auction NFT. The spends and outputs of the plan must account for all of these, so that
+/// together with the fee, the plan balances to zero.
+pub fn validate_auction_plan_balance(plan: &TransactionPlan) -> Result<()> {
+    let auction_nfts: BTreeSet<asset::Id> = plan
+        .actions
+        .iter()
+        .flat_map(|action| match action {
+            ActionPlan::ActionDutchAuctionSchedule(schedule) => {
+                vec![AuctionNft::new(schedule.description.id(), 0).asset_id()]
+            }
+            ActionPlan::ActionDutchAuctionEnd(end) => vec![
+                AuctionNft::new(end.auction_id, 0).asset_id(),
+                AuctionNft::new(end.auction_id, 1).asset_id(),
+            ],
+            ActionPlan::ActionDutchAuctionWithdraw(withdraw) => vec![
+                AuctionNft::new(withdraw.auction_id, withdraw.seq.saturating_sub(1)).asset_id(),
+                AuctionNft::new(withdraw.auction_id, withdraw.seq).asset_id(),
+            ],
+            _ => vec![],
+        })
+        .collect();
+    anyhow::ensure!(
+        !auction_nfts.is_empty(),
+        "transaction plan does not contain any auction action"
+    );
+
+    let balance = plan
+        .actions
+        .iter()
+        .fold(Balance::zero(), |balance, action| {
+            balance + action.balance()
+        })
+        - Balance::from(plan.transaction_parameters.fee.0);
+
+    let (unbalanced_nfts, unbalanced_values): (Vec<Value>, Vec<Value>) = balance
+        .required()
+        .chain(balance.provided())
+        .partition(|value| auction_nfts.contains(&value.asset_id));
+
+    anyhow::ensure!(
+        unbalanced_nfts.is_empty(),
+        "auction NFTs are not balanced by the transaction plan: {unbalanced_nfts:?}"
+    );
+    anyhow::ensure!(
+        unbalanced_values.is_empty(),
+        "transaction plan is not balanced: {unbalanced_values:?}"
+    );
+
+    Ok(())
+}
+
 impl DomainType for TransactionPlan {
     type Proto = pb::TransactionPlan;
 }
@@ -451,9 +517,19 @@ mod tests {
 
     use crate::{
         memo::MemoPlaintext,
-        plan::{CluePlan, DetectionDataPlan, MemoPlan, TransactionPlan},
+        plan::{
+            validate_auction_plan_balance, ActionPlan, CluePlan, DetectionDataPlan, MemoPlan,
+            TransactionPlan,
+        },
         TransactionParameters, WitnessData,
     };
+    use penumbra_sdk_auction::auction::{
+        dutch::{
+            actions::ActionDutchAuctionWithdrawPlan, ActionDutchAuctionSchedule,
+            DutchAuctionDescription,
+        },
+        AuctionNft,
+    };
 
     /// This isn't an exhaustive test, but we don't currently have a
     /// great way to generate actions for randomized testing.
@@ -587,4 +663,132 @@ mod tests {
         //     .expect("can build");
         // assert_eq!(plan_effect_hash, transaction.effect_hash());
     }
+
+    #[test]
+    fn auction_plan_balance() {
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let sk = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let (addr, _dtk) = sk
+            .full_viewing_key()
+            .incoming()
+            .payment_address(0u32.into());
+
+        let cache = asset::Cache::with_known_assets();
+        let description = DutchAuctionDescription {
+            input: Value {
+                amount: 100u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+            output_id: cache.get_unit("gm").unwrap().id(),
+            max_output: 1_000u64.into(),
+            min_output: 250u64.into(),
+            start_height: 100,
+            end_height: 200,
+            step_count: 10,
+            nonce: [0u8; 32],
+        };
+        let auction_id = description.id();
+
+        let plan = |actions: Vec<ActionPlan>| TransactionPlan {
+            actions,
+            transaction_parameters: TransactionParameters {
+                expiry_height: 0,
+                fee: Fee::default(),
+                chain_id: "penumbra-test".to_string(),
+            },
+            detection_data: None,
+            memo: None,
+        };
+        let spend = |value: Value| {
+            let note = Note::generate(&mut OsRng, &addr, value);
+            ActionPlan::from(SpendPlan::new(&mut OsRng, note, 0u64.into()))
+        };
+        let output =
+            |value: Value| ActionPlan::from(OutputPlan::new(&mut OsRng, value, addr.clone()));
+
+        // Scheduling: the input is spent, and the opened auction NFT is sent to an output.
+        let schedule = ActionPlan::from(ActionDutchAuctionSchedule {
+            description: description.clone(),
+        });
+        let balanced_schedule = plan(vec![
+            spend(description.input),
+            schedule.clone(),
+            output(AuctionNft::new(auction_id, 0).value()),
+        ]);
+        assert!(validate_auction_plan_balance(&balanced_schedule).is_ok());
+
+        let missing_nft_output = plan(vec![spend(description.input), schedule]);
+        assert!(validate_auction_plan_balance(&missing_nft_output).is_err());
+
+        // Withdrawing: the closed auction NFT is spent, and the reserves and the
+        // withdrawn auction NFT are sent to outputs.
+        let withdraw = ActionDutchAuctionWithdrawPlan {
+            auction_id,
+            seq: 2,
+            reserves_input: Value {
+                amount: 40u64.into(),
+                asset_id: description.input.asset_id,
+            },
+            reserves_output: Value {
+                amount: 600u64.into(),
+                asset_id: description.output_id,
+            },
+        };
+        let balanced_withdraw = plan(vec![
+            spend(AuctionNft::new(auction_id, 1).value()),
+            withdraw.clone().into(),
+            output(withdraw.reserves_input),
+            output(withdraw.reserves_output),
+            output(AuctionNft::new(auction_id, 2).value()),
+        ]);
+        assert!(validate_auction_plan_balance(&balanced_withdraw).is_ok());
+
+        // A plan without auction actions is rejected.
+        assert!(validate_auction_plan_balance(&plan(vec![])).is_err());
+    }
+
+    /// The encoded length of a plan grows by the same amount for each action added to it,
+    /// regardless of which other actions are already present.
+    #[test]
+    fn plan_encoded_len_is_additive_over_actions() {
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let sk = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        let (addr, _dtk) = sk
+            .full_viewing_key()
+            .incoming()
+            .payment_address(0u32.into());
+
+        let plan = |actions: Vec<ActionPlan>| TransactionPlan {
+            actions,
+            transaction_parameters: TransactionParameters {
+                expiry_height: 0,
+                fee: Fee::default(),
+                chain_id: "penumbra-test".to_string(),
+            },
+            detection_data: None,
+            memo: None,
+        };
+        let value = Value {
+            amount: 100u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        };
+        let spend = ActionPlan::from(SpendPlan::new(
+            &mut OsRng,
+            Note::generate(&mut OsRng, &addr, value),
+            0u64.into(),
+        ));
+        let output = ActionPlan::from(OutputPlan::new(&mut OsRng, value, addr.clone()));
+
+        let empty = plan(vec![]).encoded_len();
+        let with_spend = plan(vec![spend.clone()]).encoded_len();
+        let with_output = plan(vec![output.clone()]).encoded_len();
+        let with_both = plan(vec![spend, output]).encoded_len();
+
+        assert!(with_spend > empty);
+        assert!(with_output > empty);
+        assert_eq!(
+            with_both - empty,
+            (with_spend - empty) + (with_output - empty)
+        );
+    }
 }
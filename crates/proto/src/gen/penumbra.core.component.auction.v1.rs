@@ -1,7 +1,13 @@
 // This file is @generated by prost-build.
 /// The configuration parameters for the auction component.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct AuctionParameters {}
+pub struct AuctionParameters {
+    /// The maximum number of steps a Dutch auction can be scheduled with.
+    ///
+    /// An unset (zero) value is interpreted as the protocol maximum.
+    #[prost(uint64, tag = "1")]
+    pub max_step_count: u64,
+}
 impl ::prost::Name for AuctionParameters {
     const NAME: &'static str = "AuctionParameters";
     const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
@@ -246,6 +252,22 @@ impl ::prost::Name for DutchAuction {
         "/penumbra.core.component.auction.v1.DutchAuction".into()
     }
 }
+/// A collection of Dutch auctions, for bulk export and storage.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DutchAuctionBatch {
+    #[prost(message, repeated, tag = "1")]
+    pub auctions: ::prost::alloc::vec::Vec<DutchAuction>,
+}
+impl ::prost::Name for DutchAuctionBatch {
+    const NAME: &'static str = "DutchAuctionBatch";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.DutchAuctionBatch".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.DutchAuctionBatch".into()
+    }
+}
 /// Initiates a Dutch auction using protocol-controlled liquidity.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ActionDutchAuctionSchedule {
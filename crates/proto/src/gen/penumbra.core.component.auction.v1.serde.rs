@@ -960,8 +960,16 @@ impl serde::Serialize for AuctionParameters {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionParameters", len)?;
+        let mut len = 0;
+        if self.max_step_count != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionParameters", len)?;
+        if self.max_step_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("maxStepCount", ToString::to_string(&self.max_step_count).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -972,10 +980,13 @@ impl<'de> serde::Deserialize<'de> for AuctionParameters {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "max_step_count",
+            "maxStepCount",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            MaxStepCount,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -997,7 +1008,10 @@ impl<'de> serde::Deserialize<'de> for AuctionParameters {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "maxStepCount" | "max_step_count" => Ok(GeneratedField::MaxStepCount),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1015,10 +1029,24 @@ impl<'de> serde::Deserialize<'de> for AuctionParameters {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut max_step_count__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MaxStepCount => {
+                            if max_step_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxStepCount"));
+                            }
+                            max_step_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
                 Ok(AuctionParameters {
+                    max_step_count: max_step_count__.unwrap_or_default(),
                 })
             }
         }
@@ -1568,6 +1596,101 @@ impl<'de> serde::Deserialize<'de> for DutchAuction {
         deserializer.deserialize_struct("penumbra.core.component.auction.v1.DutchAuction", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for DutchAuctionBatch {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.auctions.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.DutchAuctionBatch", len)?;
+        if !self.auctions.is_empty() {
+            struct_ser.serialize_field("auctions", &self.auctions)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DutchAuctionBatch {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auctions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Auctions,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctions" => Ok(GeneratedField::Auctions),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DutchAuctionBatch;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.DutchAuctionBatch")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuctionBatch, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auctions__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Auctions => {
+                            if auctions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctions"));
+                            }
+                            auctions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DutchAuctionBatch {
+                    auctions: auctions__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.DutchAuctionBatch", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for DutchAuctionDescription {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -636,7 +636,7 @@ pub struct Proposal {
     #[prost(string, tag = "2")]
     pub description: ::prost::alloc::string::String,
     /// The proposal's payload.
-    #[prost(oneof = "proposal::Payload", tags = "5, 6, 7, 8, 9, 10, 11")]
+    #[prost(oneof = "proposal::Payload", tags = "5, 6, 7, 8, 9, 10, 11, 12, 13")]
     pub payload: ::core::option::Option<proposal::Payload>,
 }
 /// Nested message and enum types in `Proposal`.
@@ -790,6 +790,42 @@ pub mod proposal {
             "/penumbra.core.component.governance.v1.Proposal.UnfreezeIbcClient".into()
         }
     }
+    /// Pause or resume the scheduling of new auctions.
+    /// Auctions that were already scheduled are unaffected.
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct PauseAuctions {
+        /// If `true`, new auctions can't be scheduled until a later proposal resumes them.
+        #[prost(bool, tag = "1")]
+        pub paused: bool,
+    }
+    impl ::prost::Name for PauseAuctions {
+        const NAME: &'static str = "PauseAuctions";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "penumbra.core.component.governance.v1.Proposal.PauseAuctions".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/penumbra.core.component.governance.v1.Proposal.PauseAuctions".into()
+        }
+    }
+    /// An emergency upgrade proposal is passed as soon as enough validators approve it,
+    /// like an emergency proposal, and schedules an upgrade at the specified height.
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct EmergencyUpgrade {
+        /// The height at which to halt the chain for the upgrade.
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+    }
+    impl ::prost::Name for EmergencyUpgrade {
+        const NAME: &'static str = "EmergencyUpgrade";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "penumbra.core.component.governance.v1.Proposal.EmergencyUpgrade".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/penumbra.core.component.governance.v1.Proposal.EmergencyUpgrade".into()
+        }
+    }
     /// The proposal's payload.
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Payload {
@@ -807,6 +843,10 @@ pub mod proposal {
         FreezeIbcClient(FreezeIbcClient),
         #[prost(message, tag = "11")]
         UnfreezeIbcClient(UnfreezeIbcClient),
+        #[prost(message, tag = "12")]
+        PauseAuctions(PauseAuctions),
+        #[prost(message, tag = "13")]
+        EmergencyUpgrade(EmergencyUpgrade),
     }
 }
 impl ::prost::Name for Proposal {
@@ -1457,6 +1497,8 @@ pub enum ProposalKind {
     UpgradePlan = 5,
     FreezeIbcClient = 6,
     UnfreezeIbcClient = 7,
+    PauseAuctions = 8,
+    EmergencyUpgrade = 9,
 }
 impl ProposalKind {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1473,6 +1515,8 @@ impl ProposalKind {
             Self::UpgradePlan => "PROPOSAL_KIND_UPGRADE_PLAN",
             Self::FreezeIbcClient => "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             Self::UnfreezeIbcClient => "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            Self::PauseAuctions => "PROPOSAL_KIND_PAUSE_AUCTIONS",
+            Self::EmergencyUpgrade => "PROPOSAL_KIND_EMERGENCY_UPGRADE",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1486,6 +1530,8 @@ impl ProposalKind {
             "PROPOSAL_KIND_UPGRADE_PLAN" => Some(Self::UpgradePlan),
             "PROPOSAL_KIND_FREEZE_IBC_CLIENT" => Some(Self::FreezeIbcClient),
             "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT" => Some(Self::UnfreezeIbcClient),
+            "PROPOSAL_KIND_PAUSE_AUCTIONS" => Some(Self::PauseAuctions),
+            "PROPOSAL_KIND_EMERGENCY_UPGRADE" => Some(Self::EmergencyUpgrade),
             _ => None,
         }
     }
@@ -3290,6 +3290,12 @@ impl serde::Serialize for Proposal {
                 proposal::Payload::UnfreezeIbcClient(v) => {
                     struct_ser.serialize_field("unfreezeIbcClient", v)?;
                 }
+                proposal::Payload::PauseAuctions(v) => {
+                    struct_ser.serialize_field("pauseAuctions", v)?;
+                }
+                proposal::Payload::EmergencyUpgrade(v) => {
+                    struct_ser.serialize_field("emergencyUpgrade", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -3317,6 +3323,10 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             "freezeIbcClient",
             "unfreeze_ibc_client",
             "unfreezeIbcClient",
+            "pause_auctions",
+            "pauseAuctions",
+            "emergency_upgrade",
+            "emergencyUpgrade",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3331,6 +3341,8 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             UpgradePlan,
             FreezeIbcClient,
             UnfreezeIbcClient,
+            PauseAuctions,
+            EmergencyUpgrade,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3363,6 +3375,8 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
                             "freezeIbcClient" | "freeze_ibc_client" => Ok(GeneratedField::FreezeIbcClient),
                             "unfreezeIbcClient" | "unfreeze_ibc_client" => Ok(GeneratedField::UnfreezeIbcClient),
+                            "pauseAuctions" | "pause_auctions" => Ok(GeneratedField::PauseAuctions),
+                            "emergencyUpgrade" | "emergency_upgrade" => Ok(GeneratedField::EmergencyUpgrade),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3455,6 +3469,20 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                                 return Err(serde::de::Error::duplicate_field("unfreezeIbcClient"));
                             }
                             payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::UnfreezeIbcClient)
+;
+                        }
+                        GeneratedField::PauseAuctions => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pauseAuctions"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::PauseAuctions)
+;
+                        }
+                        GeneratedField::EmergencyUpgrade => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("emergencyUpgrade"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::EmergencyUpgrade)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -3665,6 +3693,105 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.Emergency", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for proposal::EmergencyUpgrade {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.EmergencyUpgrade", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::EmergencyUpgrade {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::EmergencyUpgrade;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.EmergencyUpgrade")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::EmergencyUpgrade, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::EmergencyUpgrade {
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.EmergencyUpgrade", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for proposal::FreezeIbcClient {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3909,6 +4036,101 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for proposal::PauseAuctions {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.paused {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.PauseAuctions", len)?;
+        if self.paused {
+            struct_ser.serialize_field("paused", &self.paused)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::PauseAuctions {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "paused",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Paused,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "paused" => Ok(GeneratedField::Paused),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::PauseAuctions;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.PauseAuctions")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::PauseAuctions, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut paused__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Paused => {
+                            if paused__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paused"));
+                            }
+                            paused__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::PauseAuctions {
+                    paused: paused__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.PauseAuctions", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for proposal::Signaling {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -4866,6 +5088,8 @@ impl serde::Serialize for ProposalKind {
             Self::UpgradePlan => "PROPOSAL_KIND_UPGRADE_PLAN",
             Self::FreezeIbcClient => "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             Self::UnfreezeIbcClient => "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            Self::PauseAuctions => "PROPOSAL_KIND_PAUSE_AUCTIONS",
+            Self::EmergencyUpgrade => "PROPOSAL_KIND_EMERGENCY_UPGRADE",
         };
         serializer.serialize_str(variant)
     }
@@ -4885,6 +5109,8 @@ impl<'de> serde::Deserialize<'de> for ProposalKind {
             "PROPOSAL_KIND_UPGRADE_PLAN",
             "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            "PROPOSAL_KIND_PAUSE_AUCTIONS",
+            "PROPOSAL_KIND_EMERGENCY_UPGRADE",
         ];
 
         struct GeneratedVisitor;
@@ -4933,6 +5159,8 @@ impl<'de> serde::Deserialize<'de> for ProposalKind {
                     "PROPOSAL_KIND_UPGRADE_PLAN" => Ok(ProposalKind::UpgradePlan),
                     "PROPOSAL_KIND_FREEZE_IBC_CLIENT" => Ok(ProposalKind::FreezeIbcClient),
                     "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT" => Ok(ProposalKind::UnfreezeIbcClient),
+                    "PROPOSAL_KIND_PAUSE_AUCTIONS" => Ok(ProposalKind::PauseAuctions),
+                    "PROPOSAL_KIND_EMERGENCY_UPGRADE" => Ok(ProposalKind::EmergencyUpgrade),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
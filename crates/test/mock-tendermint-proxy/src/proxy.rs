@@ -6,17 +6,21 @@ use {
             AbciQueryResponse, BroadcastTxAsyncRequest, BroadcastTxAsyncResponse,
             BroadcastTxSyncRequest, BroadcastTxSyncResponse, GetBlockByHeightRequest,
             GetBlockByHeightResponse, GetStatusRequest, GetStatusResponse, GetTxRequest,
-            GetTxResponse, SyncInfo,
+            GetTxResponse, SyncInfo, TxResult,
         },
     },
+    sha2::{Digest, Sha256},
     std::{
-        collections::BTreeMap,
-        sync::{Arc, RwLock},
+        collections::{BTreeMap, VecDeque},
+        future::Future,
+        pin::Pin,
+        sync::{Arc, Mutex, RwLock},
     },
     tap::{Tap, TapFallible, TapOptional},
     tendermint::{
+        abci::{request, response, Code},
         block::{Block, Height},
-        Time,
+        AppHash, Hash, Time,
     },
     tonic::Status,
     tracing::instrument,
@@ -29,12 +33,34 @@ use {
 #[derive(Default)]
 pub struct TestNodeProxy {
     inner: Arc<Inner>,
+    /// Answers the ABCI queries made through this proxy, see [`TestNodeProxy::with_query_handler`].
+    query_handler: Option<QueryHandler>,
 }
 
+/// A function answering ABCI queries, typically by reading the application's storage.
+type QueryHandler = Arc<
+    dyn Fn(request::Query) -> Pin<Box<dyn Future<Output = response::Query> + Send>> + Send + Sync,
+>;
+
 #[derive(Default)]
 struct Inner {
+    /// The [`Blocks`] that have been seen so far.
+    blocks: RwLock<Blocks>,
+    /// The transactions broadcast through this proxy, in the order they were submitted.
+    pending_txs: Mutex<VecDeque<Vec<u8>>>,
+}
+
+#[derive(Default)]
+struct Blocks {
     /// A map of the [`Blocks`] that have been seen so far, keyed by [`Height`].
-    blocks: RwLock<BTreeMap<Height, Block>>,
+    by_height: BTreeMap<Height, Block>,
+    /// The header hash of the latest block.
+    latest_block_hash: Option<Hash>,
+    /// The app hash recorded in the header of the latest block.
+    latest_app_hash: Option<AppHash>,
+    /// The location of each transaction seen so far, as the height of its block and its index
+    /// within that block, keyed by the SHA-256 hash of the transaction.
+    txs_by_hash: BTreeMap<[u8; 32], (Height, u64)>,
 }
 
 impl TestNodeProxy {
@@ -43,19 +69,64 @@ impl TestNodeProxy {
         Default::default()
     }
 
+    /// Answers the ABCI queries made through this proxy with the supplied handler.
+    ///
+    /// Tests can use this to route queries to the application under test, e.g. by forwarding
+    /// them to its info service. Without a handler, every query is answered with a non-zero
+    /// response code, as a real node does for a path that the application doesn't know about.
+    pub fn with_query_handler<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(request::Query) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = response::Query> + Send + 'static,
+    {
+        Self {
+            query_handler: Some(Arc::new(move |query| Box::pin(handler(query)))),
+            ..self
+        }
+    }
+
     /// Returns a boxed function that will add [`Blocks`] to this proxy.
     pub fn on_block_callback(&self) -> penumbra_sdk_mock_consensus::OnBlockFn {
         // Create a new reference to the shared map of blocks we've seen.
-        let Self { inner } = self;
+        let Self { inner, .. } = self;
         let inner = Arc::clone(inner);
 
         Box::new(move |block| inner.on_block(block))
     }
 
+    /// Returns the transactions broadcast through this proxy that haven't been drained yet,
+    /// in the order they were submitted.
+    pub fn pending_transactions(&self) -> Vec<Vec<u8>> {
+        self.inner.pending_txs().iter().cloned().collect()
+    }
+
+    /// Removes and returns the transactions broadcast through this proxy, in the order they
+    /// were submitted.
+    ///
+    /// A test harness can use this to include the broadcast transactions in the next block.
+    pub fn drain_pending_transactions(&self) -> Vec<Vec<u8>> {
+        self.inner.pending_txs().drain(..).collect()
+    }
+
+    /// Panics if any transaction was included in more than one of the blocks seen so far,
+    /// or more than once in the same block.
+    ///
+    /// This is useful to catch test harnesses that double-include a transaction.
+    pub fn assert_no_duplicate_txs(&self) {
+        let blocks = self.inner.blocks();
+        assert_no_duplicate_txs(
+            blocks
+                .by_height
+                .iter()
+                .flat_map(|(height, block)| block.data.iter().map(|tx| (*height, tx.as_slice()))),
+        );
+    }
+
     /// Returns the last committed block height.
     fn last_block_height(&self) -> tendermint::block::Height {
         self.inner
             .blocks()
+            .by_height
             .last_key_value()
             .map(|(height, _)| *height)
             .expect("blocks should not be empty")
@@ -65,6 +136,7 @@ impl TestNodeProxy {
     fn timestamp(&self) -> Time {
         self.inner
             .blocks()
+            .by_height
             .last_key_value()
             .map(|(_, block)| block)
             .expect("blocks should not be empty")
@@ -78,7 +150,16 @@ impl Inner {
     fn on_block(&self, block: tendermint::Block) {
         // Add this block to the proxy's book-keeping.
         let height = block.header.height;
-        self.blocks_mut()
+        let mut blocks = self.blocks_mut();
+        blocks.latest_block_hash = Some(block.header.hash());
+        blocks.latest_app_hash = Some(block.header.app_hash.clone());
+        for (index, tx) in (0u64..).zip(&block.data) {
+            blocks
+                .txs_by_hash
+                .insert(Sha256::digest(tx).into(), (height, index));
+        }
+        blocks
+            .by_height
             .insert(height, block)
             .map(|_overwritten| {
                 // ...or panic if we have been given block with duplicate heights.
@@ -89,9 +170,24 @@ impl Inner {
             });
     }
 
-    /// Acquires a write-lock on the map of blocks we have seen before.
-    fn blocks(&self) -> std::sync::RwLockReadGuard<'_, BTreeMap<Height, Block>> {
-        let Self { blocks } = self;
+    /// Queues a broadcast transaction, returning its hash.
+    fn submit_tx(&self, tx: Vec<u8>) -> Vec<u8> {
+        let hash = Sha256::digest(&tx).to_vec();
+        tracing::debug!(hash = %hex::encode_upper(&hash), "received transaction");
+        self.pending_txs().push_back(tx);
+        hash
+    }
+
+    /// Acquires a lock on the queue of broadcast transactions.
+    fn pending_txs(&self) -> std::sync::MutexGuard<'_, VecDeque<Vec<u8>>> {
+        self.pending_txs
+            .lock()
+            .expect("pending transaction lock should never be poisoned")
+    }
+
+    /// Acquires a read-lock on the blocks we have seen before.
+    fn blocks(&self) -> std::sync::RwLockReadGuard<'_, Blocks> {
+        let Self { blocks, .. } = self;
         blocks
             .tap(|_| tracing::trace!("acquiring read lock"))
             .read()
@@ -100,9 +196,9 @@ impl Inner {
             .expect("block lock should never be poisoned")
     }
 
-    /// Acquires a write-lock on the map of blocks we have seen before.
-    fn blocks_mut(&self) -> std::sync::RwLockWriteGuard<'_, BTreeMap<Height, Block>> {
-        let Self { blocks } = self;
+    /// Acquires a write-lock on the blocks we have seen before.
+    fn blocks_mut(&self) -> std::sync::RwLockWriteGuard<'_, Blocks> {
+        let Self { blocks, .. } = self;
         blocks
             .tap(|_| tracing::trace!("acquiring write lock"))
             .write()
@@ -112,13 +208,60 @@ impl Inner {
     }
 }
 
+/// Panics if any of the supplied transactions appears more than once, reporting its
+/// transaction hash and the heights of the blocks it was included in.
+fn assert_no_duplicate_txs<'a>(txs: impl IntoIterator<Item = (Height, &'a [u8])>) {
+    let mut seen = BTreeMap::<[u8; 32], Height>::new();
+    for (height, tx) in txs {
+        let hash: [u8; 32] = Sha256::digest(tx).into();
+        if let Some(first_height) = seen.insert(hash, height) {
+            panic!(
+                "transaction {} was included at height {first_height} and again at height {height}",
+                hex::encode_upper(hash)
+            );
+        }
+    }
+}
+
 #[tonic::async_trait]
 impl TendermintProxyService for TestNodeProxy {
+    /// Looks up a transaction included in one of the blocks seen so far, by its hash.
+    ///
+    /// The proxy only observes blocks, not the results of executing them, so the returned
+    /// `tx_result` is always empty.
+    #[instrument(level = "info", skip_all)]
     async fn get_tx(
         &self,
-        _req: tonic::Request<GetTxRequest>,
+        req: tonic::Request<GetTxRequest>,
     ) -> Result<tonic::Response<GetTxResponse>, Status> {
-        Err(Status::unimplemented("get_tx"))
+        let GetTxRequest { hash, prove: _ } = req.into_inner();
+        let tx_hash: [u8; 32] = hash
+            .as_slice()
+            .try_into()
+            .map_err(|_| Status::invalid_argument("transaction hash must be 32 bytes"))?;
+
+        let blocks = self.inner.blocks();
+        let (height, index) = blocks.txs_by_hash.get(&tx_hash).copied().ok_or_else(|| {
+            Status::not_found(format!(
+                "unknown transaction {}",
+                hex::encode_upper(tx_hash)
+            ))
+        })?;
+        let tx = blocks
+            .by_height
+            .get(&height)
+            .and_then(|block| block.data.get(index as usize))
+            .cloned()
+            .ok_or_else(|| Status::internal("indexed transaction is missing from its block"))?;
+
+        Ok(GetTxResponse {
+            hash,
+            height: height.value(),
+            index,
+            tx_result: Some(TxResult::default()),
+            tx,
+        })
+        .map(tonic::Response::new)
     }
 
     /// Broadcasts a transaction asynchronously.
@@ -129,13 +272,16 @@ impl TendermintProxyService for TestNodeProxy {
     )]
     async fn broadcast_tx_async(
         &self,
-        _req: tonic::Request<BroadcastTxAsyncRequest>,
+        req: tonic::Request<BroadcastTxAsyncRequest>,
     ) -> Result<tonic::Response<BroadcastTxAsyncResponse>, Status> {
+        let BroadcastTxAsyncRequest { params, req_id } = req.into_inner();
+        tracing::Span::current().record("req_id", req_id);
+
         Ok(tonic::Response::new(BroadcastTxAsyncResponse {
             code: 0,
             data: Vec::default(),
             log: String::default(),
-            hash: Vec::default(),
+            hash: self.inner.submit_tx(params),
         }))
     }
 
@@ -147,13 +293,16 @@ impl TendermintProxyService for TestNodeProxy {
     )]
     async fn broadcast_tx_sync(
         &self,
-        _req: tonic::Request<BroadcastTxSyncRequest>,
+        req: tonic::Request<BroadcastTxSyncRequest>,
     ) -> Result<tonic::Response<BroadcastTxSyncResponse>, Status> {
+        let BroadcastTxSyncRequest { params, req_id } = req.into_inner();
+        tracing::Span::current().record("req_id", req_id);
+
         Ok(tonic::Response::new(BroadcastTxSyncResponse {
             code: 0,
             data: Vec::default(),
             log: String::default(),
-            hash: Vec::default(),
+            hash: self.inner.submit_tx(params),
         }))
     }
 
@@ -166,19 +315,20 @@ impl TendermintProxyService for TestNodeProxy {
         let GetStatusRequest { .. } = req.into_inner();
         let latest_block_height = self.last_block_height().into();
         let block_ts: tendermint_proto::google::protobuf::Timestamp = self.timestamp().into();
+        let (latest_block_hash, latest_app_hash) = {
+            let blocks = self.inner.blocks();
+            (
+                blocks.latest_block_hash.map(Into::into).unwrap_or_default(),
+                blocks
+                    .latest_app_hash
+                    .clone()
+                    .map(Into::into)
+                    .unwrap_or_default(),
+            )
+        };
         let sync_info = SyncInfo {
-            latest_block_hash: self
-                .inner
-                .blocks()
-                .last_key_value()
-                .map(|(_, b)| b.header.hash().into())
-                .unwrap_or_default(),
-            latest_app_hash: self
-                .inner
-                .blocks()
-                .last_key_value()
-                .map(|(_, b)| b.header.app_hash.clone().into())
-                .unwrap_or_default(),
+            latest_block_hash,
+            latest_app_hash,
             latest_block_height,
             latest_block_time: Some(pbjson_types::Timestamp {
                 seconds: block_ts.seconds,
@@ -199,9 +349,60 @@ impl TendermintProxyService for TestNodeProxy {
     #[instrument(level = "info", skip_all)]
     async fn abci_query(
         &self,
-        _req: tonic::Request<AbciQueryRequest>,
+        req: tonic::Request<AbciQueryRequest>,
     ) -> Result<tonic::Response<AbciQueryResponse>, Status> {
-        Err(Status::unimplemented("abci_query"))
+        let AbciQueryRequest {
+            data,
+            path,
+            height,
+            prove,
+        } = req.into_inner();
+        let height: Height = height
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid height"))?;
+
+        let query = request::Query {
+            data: data.into(),
+            path,
+            height,
+            prove,
+        };
+        let rsp = match &self.query_handler {
+            Some(handler) => handler(query).await,
+            None => response::Query {
+                code: Code::from(1),
+                log: format!("unknown query path: {}", query.path),
+                height: query.height,
+                ..Default::default()
+            },
+        };
+
+        let response::Query {
+            code,
+            log,
+            info,
+            index,
+            key,
+            value,
+            proof,
+            height,
+            codespace,
+        } = rsp;
+        Ok(AbciQueryResponse {
+            code: code.value(),
+            log,
+            info,
+            index,
+            key: key.to_vec(),
+            value: value.to_vec(),
+            proof_ops: proof.map(Into::into),
+            height: height
+                .value()
+                .try_into()
+                .map_err(|_| Status::internal("response height overflowed i64"))?,
+            codespace,
+        })
+        .map(tonic::Response::new)
     }
 
     #[instrument(level = "info", skip_all)]
@@ -214,7 +415,7 @@ impl TendermintProxyService for TestNodeProxy {
         let height =
             tendermint::block::Height::try_from(height).expect("height should be less than 2^63");
 
-        let block = self.inner.blocks().get(&height).cloned();
+        let block = self.inner.blocks().by_height.get(&height).cloned();
         // the response uses the penumbra type but internally we use the tendermint type
         let proto_block = block
             .clone()
@@ -238,3 +439,194 @@ impl TendermintProxyService for TestNodeProxy {
         .map(tonic::Response::new)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a block at the supplied height, with just enough of a header for the proxy.
+    fn block(height: u32, data: Vec<Vec<u8>>) -> Block {
+        let header = tendermint::block::Header {
+            version: tendermint::block::header::Version { block: 11, app: 0 },
+            chain_id: "test-chain".parse().unwrap(),
+            height: Height::from(height),
+            time: Time::unix_epoch(),
+            last_block_id: None,
+            last_commit_hash: None,
+            data_hash: None,
+            validators_hash: Hash::None,
+            next_validators_hash: Hash::None,
+            consensus_hash: Hash::None,
+            app_hash: AppHash::try_from(vec![height as u8; 32]).unwrap(),
+            last_results_hash: None,
+            evidence_hash: None,
+            proposer_address: tendermint::account::Id::new([0u8; 20]),
+        };
+        Block::new(header, data, Default::default(), None)
+    }
+
+    #[tokio::test]
+    async fn status_reports_the_latest_hashes() {
+        let proxy = TestNodeProxy::default();
+        let mut on_block = proxy.on_block_callback();
+        on_block(block(1, vec![]));
+        let latest = block(2, vec![b"tx".to_vec()]);
+        on_block(latest.clone());
+
+        let sync_info = proxy
+            .get_status(tonic::Request::new(GetStatusRequest::default()))
+            .await
+            .unwrap()
+            .into_inner()
+            .sync_info
+            .unwrap();
+        assert_eq!(sync_info.latest_block_height, 2);
+        assert_eq!(
+            sync_info.latest_block_hash,
+            Vec::<u8>::from(latest.header.hash())
+        );
+        assert_eq!(sync_info.latest_app_hash, vec![2u8; 32]);
+    }
+
+    fn query(path: &str) -> tonic::Request<AbciQueryRequest> {
+        tonic::Request::new(AbciQueryRequest {
+            data: b"key".to_vec(),
+            path: path.to_string(),
+            height: 3,
+            prove: true,
+        })
+    }
+
+    #[tokio::test]
+    async fn abci_queries_are_routed_to_the_handler() {
+        let proxy =
+            TestNodeProxy::default().with_query_handler(|query: request::Query| async move {
+                if query.path != "state/key" {
+                    return response::Query {
+                        code: Code::from(1),
+                        ..Default::default()
+                    };
+                }
+                response::Query {
+                    key: query.data.clone(),
+                    value: b"value".to_vec().into(),
+                    proof: query
+                        .prove
+                        .then(|| tendermint::merkle::proof::ProofOps { ops: Vec::new() }),
+                    height: query.height,
+                    ..Default::default()
+                }
+            });
+
+        let rsp = proxy
+            .abci_query(query("state/key"))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(rsp.code, 0);
+        assert_eq!(rsp.key, b"key");
+        assert_eq!(rsp.value, b"value");
+        assert_eq!(rsp.height, 3);
+        assert!(rsp.proof_ops.is_some());
+
+        let rsp = proxy
+            .abci_query(query("unknown"))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_ne!(rsp.code, 0);
+    }
+
+    #[tokio::test]
+    async fn abci_queries_fail_without_a_handler() {
+        let rsp = TestNodeProxy::default()
+            .abci_query(query("state/key"))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_ne!(rsp.code, 0);
+        assert!(rsp.value.is_empty());
+    }
+
+    #[tokio::test]
+    async fn txs_are_found_by_hash() {
+        let proxy = TestNodeProxy::default();
+        let mut on_block = proxy.on_block_callback();
+        on_block(block(1, vec![b"first".to_vec()]));
+        on_block(block(2, vec![b"second".to_vec(), b"third".to_vec()]));
+
+        let get_tx = |tx: &[u8]| {
+            proxy.get_tx(tonic::Request::new(GetTxRequest {
+                hash: Sha256::digest(tx).to_vec(),
+                prove: false,
+            }))
+        };
+
+        let rsp = get_tx(b"third").await.unwrap().into_inner();
+        assert_eq!(rsp.height, 2);
+        assert_eq!(rsp.index, 1);
+        assert_eq!(rsp.tx, b"third");
+        assert!(rsp.tx_result.is_some());
+
+        let status = get_tx(b"unknown").await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn broadcast_txs_are_queued() {
+        let proxy = TestNodeProxy::default();
+        let rsp = proxy
+            .broadcast_tx_sync(tonic::Request::new(BroadcastTxSyncRequest {
+                params: b"first".to_vec(),
+                req_id: 1,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(rsp.hash, Sha256::digest(b"first").to_vec());
+        proxy
+            .broadcast_tx_async(tonic::Request::new(BroadcastTxAsyncRequest {
+                params: b"second".to_vec(),
+                req_id: 2,
+            }))
+            .await
+            .unwrap();
+
+        let submitted = vec![b"first".to_vec(), b"second".to_vec()];
+        assert_eq!(proxy.pending_transactions(), submitted);
+        assert_eq!(proxy.drain_pending_transactions(), submitted);
+        assert!(proxy.pending_transactions().is_empty());
+    }
+
+    #[test]
+    fn distinct_txs_pass() {
+        let proxy = TestNodeProxy::default();
+        let mut on_block = proxy.on_block_callback();
+        on_block(block(1, vec![b"first".to_vec(), b"second".to_vec()]));
+        on_block(block(2, vec![b"third".to_vec()]));
+
+        proxy.assert_no_duplicate_txs();
+    }
+
+    #[test]
+    #[should_panic(expected = "again at height 3")]
+    fn txs_repeated_across_blocks_panic() {
+        let proxy = TestNodeProxy::default();
+        let mut on_block = proxy.on_block_callback();
+        on_block(block(1, vec![b"first".to_vec()]));
+        on_block(block(2, vec![b"second".to_vec()]));
+        on_block(block(3, vec![b"first".to_vec()]));
+
+        proxy.assert_no_duplicate_txs();
+    }
+
+    #[test]
+    #[should_panic(expected = "at height 1 and again at height 1")]
+    fn txs_repeated_within_a_block_panic() {
+        let proxy = TestNodeProxy::default();
+        let mut on_block = proxy.on_block_callback();
+        on_block(block(1, vec![b"first".to_vec(), b"first".to_vec()]));
+
+        proxy.assert_no_duplicate_txs();
+    }
+}
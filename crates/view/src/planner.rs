@@ -214,6 +214,50 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Schedule a Dutch auction, funding its input by spending notes from `records`.
+    ///
+    /// Rather than spending the largest notes first, as [`Planner::plan`] does, this selects
+    /// the notes whose total exceeds the auction input by as little as possible, preferring
+    /// fewer notes when several selections leave the same change. This keeps change outputs
+    /// small, or avoids them entirely, which limits the number of notes a wallet accumulates.
+    ///
+    /// Notes of other assets are ignored.
+    #[instrument(skip(self, records))]
+    pub fn dutch_auction_schedule_with_least_change(
+        &mut self,
+        description: DutchAuctionDescription,
+        records: Vec<SpendableNoteRecord>,
+    ) -> Result<&mut Self> {
+        let input = description.input;
+        let mut records = records
+            .into_iter()
+            .filter(|record| {
+                record.note.asset_id() == input.asset_id && record.note.amount() > Amount::zero()
+            })
+            .collect::<Vec<_>>();
+        records.sort_by(|a, b| b.note.amount().cmp(&a.note.amount()));
+
+        let amounts = records
+            .iter()
+            .map(|record| record.note.amount())
+            .collect::<Vec<_>>();
+        let available = amounts
+            .iter()
+            .fold(Amount::zero(), |total, amount| total.saturating_add(amount));
+        anyhow::ensure!(
+            available >= input.amount,
+            "insufficient notes to fund auction: need {}, have {}",
+            input.amount,
+            available
+        );
+
+        for index in select_least_change(&amounts, input.amount) {
+            let record = &records[index];
+            self.spend(record.note.clone(), record.position);
+        }
+        Ok(self.dutch_auction_schedule(description))
+    }
+
     /// Ends a Dutch auction.
     #[instrument(skip(self))]
     pub fn dutch_auction_end(&mut self, auction_id: AuctionId) -> &mut Self {
@@ -668,3 +712,94 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         Ok(plan)
     }
 }
+
+/// The maximum number of note subsets explored by [`select_least_change`].
+const MAX_FUNDING_SEARCH_STEPS: usize = 100_000;
+
+/// Select indices into `amounts`, which must be sorted in descending order and sum to at
+/// least `target`, whose total covers `target` with the least change.
+///
+/// This is a depth-first search over subsets, bounded by [`MAX_FUNDING_SEARCH_STEPS`].
+/// The search starts with the largest amounts, so the first selection it finds is the
+/// greedy one, and every later selection can only improve on it.
+fn select_least_change(amounts: &[Amount], target: Amount) -> Vec<usize> {
+    // The total of the amounts from each index onwards, used to prune subsets that can no
+    // longer reach the target.
+    let mut remaining = vec![Amount::zero(); amounts.len() + 1];
+    for (i, amount) in amounts.iter().enumerate().rev() {
+        remaining[i] = remaining[i + 1].saturating_add(amount);
+    }
+
+    // Each entry of the stack is a subset left to explore: the next index to decide on, the
+    // total so far, and the selection it extends, as a prefix length of `current` followed
+    // by the index it adds, if any. The stack holds at most two entries per amount.
+    let mut stack = vec![(0, Amount::zero(), 0, None)];
+    let mut current: Vec<usize> = Vec::new();
+    let mut best: Option<(Amount, Vec<usize>)> = None;
+    let mut steps = 0;
+
+    while let Some((index, total, prefix, added)) = stack.pop() {
+        current.truncate(prefix);
+        current.extend(added);
+
+        if total >= target {
+            let change = total.saturating_sub(&target);
+            let improves = match &best {
+                None => true,
+                Some((best_change, best)) => (change, current.len()) < (*best_change, best.len()),
+            };
+            if improves {
+                best = Some((change, current.clone()));
+            }
+            continue;
+        }
+        if matches!(best, Some((change, _)) if change == Amount::zero())
+            || steps >= MAX_FUNDING_SEARCH_STEPS
+        {
+            break;
+        }
+        if index == amounts.len() || total.saturating_add(&remaining[index]) < target {
+            continue;
+        }
+        steps += 1;
+
+        // Push the branch without this amount first, so the branch with it is explored first.
+        stack.push((index + 1, total, current.len(), None));
+        stack.push((
+            index + 1,
+            total.saturating_add(&amounts[index]),
+            current.len(),
+            Some(index),
+        ));
+    }
+
+    best.map(|(_, selected)| selected).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn funding_selection_leaves_least_change() {
+        let select = |amounts: &[u64], target: u64| {
+            let amounts: Vec<Amount> = amounts.iter().map(|&amount| amount.into()).collect();
+            let mut selected: Vec<u64> = select_least_change(&amounts, target.into())
+                .into_iter()
+                .map(|i| amounts[i].value() as u64)
+                .collect();
+            selected.sort();
+            selected
+        };
+
+        // Spending the largest amounts first would select 60 + 50, leaving 10 in change.
+        assert_eq!(select(&[60, 50, 45, 30, 10], 100), vec![10, 30, 60]);
+        // Without an exact match, the smallest excess wins, then the fewest amounts.
+        assert_eq!(select(&[70, 40, 35, 5], 100), vec![35, 70]);
+        assert_eq!(select(&[100, 60, 50], 100), vec![100]);
+        // Searching many amounts neither overflows the stack nor runs unbounded.
+        let many = vec![3u64; 10_000];
+        assert_eq!(select(&many, 30_000).len(), 10_000);
+        assert_eq!(select(&many, 10).iter().sum::<u64>(), 12);
+    }
+}